@@ -0,0 +1,210 @@
+// Description: background model options (-b, -bh and -bx), edited one parameter at a time.
+// Parameter meanings and defaults are taken from quantBackground.cpp. The list types here also serve other numeric list options such as -T.
+
+use serde::{Deserialize, Serialize};
+
+/// One position in an option's comma-separated list of numbers
+pub struct Param {
+    pub label: &'static str,
+    pub meaning: &'static str,
+    pub min: Option<f32>,
+    pub max: Option<f32>,
+    // Channel numbers and iteration counts are truncated to whole numbers by PIQUANT
+    pub whole: bool
+}
+
+/// An option taking a list of numbers, and the parameters in it, in order
+pub struct Family {
+    pub option: &'static str,
+    pub title: &'static str,
+    pub description: &'static str,
+    pub params: &'static [Param],
+    // Values PIQUANT uses when the option isn't given
+    pub defaults: &'static [f32]
+}
+
+// -b and -bh take the same list, for the low and high energy backgrounds
+const SNIP_PARAMS: &[Param] = &[
+    Param { label: "Start channel", meaning: "First channel for SNIP; 0 starts at the configuration's minimum energy, negative uses the calculated continuum instead of SNIP", min: None, max: None, whole: true },
+    Param { label: "Width", meaning: "SNIP filter width in channels, 0 from the detector resolution; for the continuum, its scale (>0 fixed, 0 fitted, <0 scaled under peaks by this many sigma)", min: None, max: None, whole: false },
+    Param { label: "Iterations", meaning: "SNIP iterations, 0 for 24", min: Some(0.0), max: None, whole: true },
+    Param { label: "Zone 2 start", meaning: "First channel of the second SNIP zone", min: Some(0.0), max: None, whole: true },
+    Param { label: "Zone 2 end", meaning: "Last channel of the second SNIP zone", min: Some(0.0), max: None, whole: true },
+    Param { label: "Zone 2 width", meaning: "SNIP filter width in the second zone; a 0 in any zone 2 entry gives a single zone", min: Some(0.0), max: None, whole: true },
+    Param { label: "Scale", meaning: "SNIP scale: >0 fixed factor, 0 fitted, <0 scaled under peaks by this many sigma", min: None, max: None, whole: false }
+];
+
+pub const LOW: Family = Family {
+    option: "-b",
+    title: "Background",
+    description: "Low energy background; on its own, one background for the whole spectrum",
+    params: SNIP_PARAMS,
+    defaults: &[-1.0, -5.0]
+};
+
+pub const HIGH: Family = Family {
+    option: "-bh",
+    title: "High energy background",
+    description: "Background above the crossover energy",
+    params: SNIP_PARAMS,
+    defaults: &[0.0, 10.0, 60.0, 910.0, 1260.0, 6.0, 1.0]
+};
+
+pub const CROSSOVER: Family = Family {
+    option: "-bx",
+    title: "Crossover",
+    description: "Where the low and high energy backgrounds meet",
+    params: &[
+        Param { label: "Energy (eV)", meaning: "Crossover energy, 0 for a single background", min: Some(0.0), max: None, whole: false },
+        Param { label: "Half width (eV)", meaning: "Half width of the blend between the two backgrounds", min: Some(0.0), max: None, whole: false }
+    ],
+    defaults: &[7150.0, 150.0]
+};
+
+pub const FAMILIES: [&Family; 3] = [&LOW, &HIGH, &CROSSOVER];
+
+/// Entered values for one list option; blank entries are left out
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct ParamValues {
+    pub enabled: bool,
+    pub values: Vec<String>
+}
+
+/// Entered values for all three background options
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Background {
+    pub low: ParamValues,
+    pub high: ParamValues,
+    pub crossover: ParamValues
+}
+
+impl Background {
+    pub fn values(&self, family: &Family) -> &ParamValues {
+        match family.option {
+            "-bh" => &self.high,
+            "-bx" => &self.crossover,
+            _ => &self.low
+        }
+    }
+
+    pub fn values_mut(&mut self, family: &Family) -> &mut ParamValues {
+        match family.option {
+            "-bh" => &mut self.high,
+            "-bx" => &mut self.crossover,
+            _ => &mut self.low
+        }
+    }
+
+    /// Option strings for each enabled family with at least one value, among those a task accepts
+    pub fn args(&self, accepts: impl Fn(&str) -> bool) -> Vec<String> {
+        FAMILIES.iter()
+            .filter(|family| accepts(family.option))
+            .filter_map(|family| option_string(family, self.values(family)))
+            .collect()
+    }
+
+    /// Problems with the entered values, one per bad entry, among the options a task accepts
+    pub fn errors(&self, accepts: impl Fn(&str) -> bool) -> Vec<String> {
+        FAMILIES.iter()
+            .filter(|family| accepts(family.option))
+            .flat_map(|family| errors(family, self.values(family)))
+            .collect()
+    }
+}
+
+/// Problems with the values entered for an enabled option, one per bad entry
+pub fn errors(family: &Family, values: &ParamValues) -> Vec<String> {
+    if !values.enabled {
+        return Vec::new();
+    }
+    family.params.iter().enumerate().filter_map(|(i, param)| {
+        let value = values.values.get(i).map(String::as_str).unwrap_or("");
+        check_value(param, value).err().map(|e| format!("{} {}: {}", family.option, param.label, e))
+    }).collect()
+}
+
+/// The option as PIQUANT expects it, e.g. "-bh,0,12,60,910"; None if disabled or empty
+pub fn option_string(family: &Family, values: &ParamValues) -> Option<String> {
+    if !values.enabled {
+        return None;
+    }
+    let entries: Vec<&str> = values.values.iter().take(family.params.len()).map(|v| v.trim()).collect();
+    // Blanks after the last value keep PIQUANT's defaults; blanks before it would be read as 0, so they're filled in
+    let used = entries.iter().rposition(|v| !v.is_empty())? + 1;
+    let filled: Vec<String> = entries[..used].iter().enumerate()
+        .map(|(index, v)| if v.is_empty() { blank_value(family, index) } else { v.to_string() })
+        .collect();
+    Some(format!("{},{}", family.option, filled.join(",")))
+}
+
+/// What a blank entry before the last value is sent as: PIQUANT's default where known, otherwise the 0 it would read
+pub fn blank_value(family: &Family, index: usize) -> String {
+    family.defaults.get(index).map(|d| d.to_string()).unwrap_or_else(|| String::from("0"))
+}
+
+/// Check a single entry; blank is always allowed
+pub fn check_value(param: &Param, value: &str) -> Result<(), String> {
+    let value = value.trim();
+    if value.is_empty() {
+        return Ok(());
+    }
+    let number: f32 = value.parse().map_err(|_| format!("\"{}\" is not a number", value))?;
+    if !number.is_finite() {
+        return Err(format!("\"{}\" is not a number", value));
+    }
+    if let Some(min) = param.min {
+        if number < min {
+            return Err(format!("must be at least {}", min));
+        }
+    }
+    if let Some(max) = param.max {
+        if number > max {
+            return Err(format!("must be at most {}", max));
+        }
+    }
+    if param.whole && number.fract() != 0.0 {
+        return Err(String::from("must be a whole number"));
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn entered(values: &[&str]) -> ParamValues {
+        ParamValues { enabled: true, values: values.iter().map(|v| v.to_string()).collect() }
+    }
+
+    #[test]
+    fn option_strings() {
+        assert_eq!(option_string(&HIGH, &entered(&[" 0", "12 ", "", "", "", "", ""])).as_deref(), Some("-bh,0,12"));
+        // Blanks before a value get the default, or 0 where there isn't one
+        assert_eq!(option_string(&HIGH, &entered(&["0", "12", "", "910"])).as_deref(), Some("-bh,0,12,60,910"));
+        assert_eq!(option_string(&LOW, &entered(&["", "", "  ", "", "", "", "0.5"])).as_deref(), Some("-b,-1,-5,0,0,0,0,0.5"));
+        // Entries past the option's parameters are ignored
+        assert_eq!(option_string(&CROSSOVER, &entered(&["7000", "", "99"])).as_deref(), Some("-bx,7000"));
+    }
+
+    #[test]
+    fn nothing_to_send() {
+        assert_eq!(option_string(&LOW, &entered(&["", " ", ""])), None);
+        assert_eq!(option_string(&LOW, &entered(&[])), None);
+        let mut values = entered(&["5"]);
+        values.enabled = false;
+        assert_eq!(option_string(&LOW, &values), None);
+    }
+
+    #[test]
+    fn checking_values() {
+        let iterations = &SNIP_PARAMS[2];
+        assert!(check_value(iterations, " ").is_ok());
+        assert!(check_value(iterations, " 24 ").is_ok());
+        assert_eq!(check_value(iterations, "2.5"), Err(String::from("must be a whole number")));
+        assert_eq!(check_value(iterations, "-1"), Err(String::from("must be at least 0")));
+        assert_eq!(check_value(iterations, "NaN"), Err(String::from("\"NaN\" is not a number")));
+        assert_eq!(errors(&HIGH, &entered(&["x"])), ["-bh Start channel: \"x\" is not a number"]);
+    }
+}
@@ -0,0 +1,148 @@
+// Description: PIQUANT version handling and the table of which sub-commands and options each version supports.
+
+use std::fmt;
+
+/// A PIQUANT version as printed by `PIQUANT ver`, e.g. "4.0.6"
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct Version {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32
+}
+
+impl Version {
+    pub const fn new(major: u32, minor: u32, patch: u32) -> Version {
+        Version { major, minor, patch }
+    }
+
+    /// Parse a version string; branch suffixes ("3.1.2-V3_bkg_options") and non-numeric parts ("2.alpha.79") are ignored
+    pub fn parse(text: &str) -> Option<Version> {
+        let numbers = text.trim().split('-').next()?;
+        let mut parts = numbers.split('.');
+        let major = parts.next()?.parse().ok()?;
+        let minor = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+        let patch = parts.next().and_then(|p| p.parse().ok()).unwrap_or(0);
+        Some(Version::new(major, minor, patch))
+    }
+}
+
+impl fmt::Display for Version {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+    }
+}
+
+/// A sub-command or option and the first PIQUANT release that accepts it
+struct Feature {
+    name: &'static str,
+    // None when the change log doesn't say which release it first appeared in
+    since: Option<Version>,
+    // Extra warning that applies to every version
+    note: Option<&'static str>
+}
+
+// Sub-commands, taken from the change log in PIQUANT_CommandLine.cpp and parse_arguments.cpp
+const SUBCOMMANDS: &[Feature] = &[
+    Feature { name: "ene", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "plo", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "pri", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "calc", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "cal", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "qua", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "map", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "com", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "sum", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "ems", since: Some(Version::new(2, 10, 0)), note: None },
+    Feature { name: "eva", since: Some(Version::new(2, 41, 0)), note: None },
+    Feature { name: "opt", since: Some(Version::new(3, 0, 1)), note: None },
+    Feature { name: "fits", since: Some(Version::new(2, 0, 0)), note: Some("not listed in PIQUANT's help, so may change without notice") }
+];
+
+// Options, keyed by the text before the first comma
+const OPTIONS: &[Feature] = &[
+    Feature { name: "-e", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "-b", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "-d", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "-m", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "-q", since: Some(Version::new(2, 22, 0)), note: None },
+    Feature { name: "-f", since: Some(Version::new(2, 41, 0)), note: None },
+    Feature { name: "-g", since: Some(Version::new(2, 41, 0)), note: None },
+    Feature { name: "-v", since: Some(Version::new(3, 0, 1)), note: None },
+    Feature { name: "-s", since: Some(Version::new(3, 0, 1)), note: None },
+    Feature { name: "-u", since: Some(Version::new(3, 0, 4)), note: None },
+    Feature { name: "-w", since: Some(Version::new(3, 0, 4)), note: None },
+    Feature { name: "-bh", since: Some(Version::new(3, 1, 2)), note: None },
+    Feature { name: "-bx", since: Some(Version::new(3, 1, 2)), note: None },
+    Feature { name: "-T", since: Some(Version::new(3, 1, 3)), note: None },
+    // Added in June and July 2021 after 3.1.3, with no release recorded, so not checked
+    Feature { name: "-n", since: None, note: None },
+    Feature { name: "-Fe", since: None, note: None },
+    // Not dated in the change log; assumed available in every release the GUI supports
+    Feature { name: "-c", since: Some(Version::new(2, 0, 0)), note: None },
+    Feature { name: "-t", since: Some(Version::new(2, 0, 0)), note: None }
+];
+
+/// Check an argument vector against the PIQUANT version in use, returning a warning for each problem found
+pub fn check(version: Option<Version>, args: &[String]) -> Vec<String> {
+    let mut warnings = Vec::new();
+    let Some(sub_command) = args.first() else {
+        return warnings;
+    };
+
+    if let Some(feature) = SUBCOMMANDS.iter().find(|f| f.name == sub_command) {
+        check_feature(feature, "Sub-command", version, &mut warnings);
+    }
+
+    for arg in args.iter().skip(1).filter(|a| a.starts_with('-')) {
+        let option = arg.split(',').next().unwrap_or(arg);
+        // A negative number is a value, e.g. from the CLI arguments field, not an option
+        if option.parse::<f64>().is_ok() {
+            continue;
+        }
+        match OPTIONS.iter().find(|f| f.name == option) {
+            Some(feature) => check_feature(feature, "Option", version, &mut warnings),
+            None => warnings.push(format!("Option {} is not recognised by any known PIQUANT version", option))
+        }
+    }
+    warnings
+}
+
+/// Add warnings for a single sub-command or option
+fn check_feature(feature: &Feature, kind: &str, version: Option<Version>, warnings: &mut Vec<String>) {
+    if let Some(note) = feature.note {
+        warnings.push(format!("{} {} is {}", kind, feature.name, note));
+    }
+    if let (Some(version), Some(since)) = (version, feature.since) {
+        if version < since {
+            warnings.push(format!("{} {} needs PIQUANT {} or later (using {})", kind, feature.name, since, version));
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn versions() {
+        assert_eq!(Version::parse("3.1.2-V3_bkg_options"), Some(Version::new(3, 1, 2)));
+        assert_eq!(Version::parse("2.alpha.79"), Some(Version::new(2, 0, 79)));
+        assert_eq!(Version::parse("unknown"), None);
+    }
+
+    #[test]
+    fn option_names() {
+        let version = Some(Version::new(3, 0, 4));
+        // Values after the option name, negative numbers included, are not options
+        assert!(check(version, &args(&["qua", "-b,-1,-5", "-e,-20,7.5", "-5", "-0.25"])).is_empty());
+        assert_eq!(check(version, &args(&["qua", "-T,0.5"])), ["Option -T needs PIQUANT 3.1.3 or later (using 3.0.4)"]);
+        // Options without a known first release are never gated
+        assert!(check(Some(Version::new(2, 0, 0)), &args(&["qua", "-Fe,0.2", "-n,100"])).is_empty());
+        assert_eq!(check(None, &args(&["qua", "-zz,1"])), ["Option -zz is not recognised by any known PIQUANT version"]);
+        assert_eq!(check(Some(Version::new(2, 40, 0)), &args(&["eva"])), ["Sub-command eva needs PIQUANT 2.41.0 or later (using 2.40.0)"]);
+    }
+}
@@ -0,0 +1,60 @@
+// Description: detector selection (-d) and detector shelf (-T) options.
+// PIXL spectrum files hold one spectrum per detector, A first; see quantCombineSpectra.cpp and XrayDetector.h.
+
+use serde::{Deserialize, Serialize};
+
+use crate::background::{Family, Param};
+
+/// Which detectors' spectra a run uses
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
+pub enum Detector {
+    // Spectra from all detectors summed, PIQUANT's default
+    #[default]
+    Combined,
+    A,
+    B,
+    // One run per detector
+    Separately
+}
+
+impl Detector {
+    /// Name shown next to the selector
+    pub fn label(&self) -> &'static str {
+        match self {
+            Detector::Combined => "A and B combined",
+            Detector::A => "A only",
+            Detector::B => "B only",
+            Detector::Separately => "A and B separately"
+        }
+    }
+
+    /// The -d option for a single-detector run
+    pub fn arg(&self) -> Option<String> {
+        match self {
+            Detector::A => Some(String::from("-d,0")),
+            Detector::B => Some(String::from("-d,1")),
+            Detector::Combined | Detector::Separately => None
+        }
+    }
+
+    /// Detectors to run one after the other, with the suffix added to their output files
+    pub fn split(&self) -> Option<[(Detector, &'static str); 2]> {
+        match self {
+            Detector::Separately => Some([(Detector::A, "_A"), (Detector::B, "_B")]),
+            _ => None
+        }
+    }
+}
+
+// Values of 0 or less keep the defaults in XrayDetector.h
+pub const SHELF: Family = Family {
+    option: "-T",
+    title: "Detector shelf",
+    description: "Shelf from electron escape in the detector; 0 keeps the default",
+    params: &[
+        Param { label: "Factor", meaning: "Multiplies the overall shelf size", min: Some(0.0), max: None, whole: false },
+        Param { label: "Slope", meaning: "Slope of the shelf against electron loss energy", min: Some(0.0), max: None, whole: false },
+        Param { label: "Slope start", meaning: "Fraction of the electron energy where the slope starts", min: Some(0.0), max: Some(1.0), whole: false }
+    ],
+    defaults: &[1.0, 0.0, 0.0]
+};
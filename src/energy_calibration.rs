@@ -0,0 +1,52 @@
+// Description: energy calibration override (-e), and reading the calibration an Energy Calibration run reports.
+// The option is applied in setup_spectrum_parameters.cpp; the "(-e,start,eV/ch)" output line comes from PIQUANT_CommandLine.cpp.
+
+use serde::{Deserialize, Serialize};
+
+/// Energy calibration to use instead of the one in the spectrum file
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct EnergyCalibration {
+    pub enabled: bool,
+    // eV at the start of the first channel
+    pub start: String,
+    pub per_channel: String
+}
+
+impl EnergyCalibration {
+    /// The -e option, when the override is on
+    pub fn arg(&self) -> Option<String> {
+        if !self.enabled {
+            return None;
+        }
+        Some(format!("-e,{},{}", self.start.trim(), self.per_channel.trim()))
+    }
+
+    /// Problems with the entered values
+    pub fn errors(&self) -> Vec<String> {
+        let mut errors = Vec::new();
+        if !self.enabled {
+            return errors;
+        }
+        let start = self.start.trim();
+        if !start.parse::<f32>().is_ok_and(f32::is_finite) {
+            errors.push(format!("-e: eV start \"{}\" is not a number", start));
+        }
+        // PIQUANT rejects a calibration without a positive eV/channel
+        let per_channel = self.per_channel.trim();
+        match per_channel.parse::<f32>() {
+            Ok(value) if value.is_finite() && value > 0.0 => {}
+            Ok(value) if value.is_finite() => errors.push(String::from("-e: eV/ch must be more than 0")),
+            _ => errors.push(format!("-e: eV/ch \"{}\" is not a number", per_channel))
+        }
+        errors
+    }
+}
+
+/// The last calibration an Energy Calibration run printed, as (eV start, eV/ch)
+pub fn from_output(output: &str) -> Option<(String, String)> {
+    let start = output.rfind("(-e,")? + "(-e,".len();
+    let end = start + output[start..].find(')')?;
+    let (ev_start, per_channel) = output[start..end].split_once(',')?;
+    Some((ev_start.trim().to_string(), per_channel.trim().to_string()))
+}
@@ -0,0 +1,110 @@
+// Description: fit and quantification switches: -f, -g, -v, -c, -n and -Fe.
+// Option handling is taken from parse_arguments.cpp, carbonates from parse_element_list.cpp and XrayMaterial.cpp.
+
+use serde::{Deserialize, Serialize};
+
+// Options set from this group
+pub const OPTIONS: [&str; 6] = ["-f", "-g", "-v", "-c", "-n", "-Fe"];
+
+// Elements XrayMaterial gives a carbonate formula; any others stay oxides
+pub const CARBONATE_ELEMENTS: [&str; 5] = ["Mg", "Ca", "Mn", "Fe", "Sr"];
+
+/// Fit option settings; typed values are kept as entered and checked before a run
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct FitOptions {
+    pub freeze_energy: bool,
+    pub freeze_width: bool,
+    pub convolve_compton: bool,
+    pub carbonates: bool,
+    // Elements to treat as carbonates; empty for every element that can be
+    pub carbonate_elements: String,
+    pub normalize: bool,
+    pub normalization: String,
+    pub iron_ratio_enabled: bool,
+    pub iron_ratio: String
+}
+
+impl FitOptions {
+    /// Option strings for the switches that are on, among those a task accepts
+    pub fn args(&self, accepts: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut args = Vec::new();
+        if self.freeze_energy && accepts("-f") {
+            args.push(String::from("-f"));
+        }
+        if self.freeze_width && accepts("-g") {
+            args.push(String::from("-g"));
+        }
+        if self.convolve_compton && accepts("-v") {
+            args.push(String::from("-v"));
+        }
+        // Chosen elements are marked in the element list instead, see element_list
+        if self.carbonates && self.carbonate_elements.trim().is_empty() && accepts("-c") {
+            args.push(String::from("-c"));
+        }
+        if self.normalize && accepts("-n") {
+            args.push(format!("-n,{}", self.normalization.trim()));
+        }
+        if self.iron_ratio_enabled && accepts("-Fe") {
+            args.push(format!("-Fe,{}", self.iron_ratio.trim()));
+        }
+        args
+    }
+
+    /// Problems with the entered values, among the options a task accepts
+    pub fn errors(&self, element_list: &str, accepts: impl Fn(&str) -> bool) -> Vec<String> {
+        let mut errors = Vec::new();
+        if self.carbonates && accepts("-c") {
+            let listed: Vec<&str> = entries(element_list).map(symbol).collect();
+            for element in entries(&self.carbonate_elements) {
+                if !CARBONATE_ELEMENTS.iter().any(|c| c.eq_ignore_ascii_case(element)) {
+                    errors.push(format!("-c: {} doesn't form a carbonate; only {} do", element, CARBONATE_ELEMENTS.join(", ")));
+                } else if !listed.iter().any(|l| l.eq_ignore_ascii_case(element)) {
+                    errors.push(format!("-c: {} is not in the element fit controls", element));
+                }
+            }
+        }
+        if self.normalize && accepts("-n") {
+            let value = self.normalization.trim();
+            match value.parse::<i32>() {
+                Ok(percent) if percent > 0 => {}
+                Ok(_) => errors.push(String::from("-n: the element sum must be more than 0%")),
+                Err(_) => errors.push(format!("-n: \"{}\" is not a whole number of percent", value))
+            }
+        }
+        if self.iron_ratio_enabled && accepts("-Fe") {
+            let value = self.iron_ratio.trim();
+            match value.parse::<f32>() {
+                Ok(ratio) if ratio.is_finite() && ratio >= 0.0 => {}
+                Ok(ratio) if ratio.is_finite() => errors.push(String::from("-Fe: the oxygen ratio must be at least 0")),
+                _ => errors.push(format!("-Fe: \"{}\" is not a number", value))
+            }
+        }
+        errors
+    }
+
+    /// The element list with the chosen carbonate elements moved after a "CO3" entry
+    pub fn element_list(&self, element_list: &str, accepts: impl Fn(&str) -> bool) -> String {
+        if !self.carbonates || !accepts("-c") || self.carbonate_elements.trim().is_empty() {
+            return element_list.to_string();
+        }
+        let chosen = |entry: &&str| entries(&self.carbonate_elements).any(|c| c.eq_ignore_ascii_case(symbol(entry)));
+        // PIQUANT treats every entry after "CO3" as a carbonate
+        let (carbonates, others): (Vec<&str>, Vec<&str>) = entries(element_list).partition(chosen);
+        let mut list = others;
+        list.push("CO3");
+        list.extend(carbonates);
+        list.join(",")
+    }
+}
+
+/// Entries of an element list, split the way PIQUANT splits them
+pub fn entries(list: &str) -> impl Iterator<Item = &str> {
+    list.split([',', ' ']).map(str::trim).filter(|e| !e.is_empty())
+}
+
+/// The element symbol an element list entry starts with, e.g. "Fe" from "Fe_K" or "Fe=12%"
+pub fn symbol(entry: &str) -> &str {
+    let end = entry.find(['_', '=']).unwrap_or(entry.len().min(2));
+    entry.get(..end).unwrap_or(entry).trim()
+}
@@ -1,149 +1,234 @@
 // Description: file containing public functions 
 // use log::error;
-use std::path::PathBuf;
+use std::env;
+use std::path::{Component, Path, PathBuf};
 use rfd::FileDialog;
-use eframe::egui::Color32;
 
-use crate::gui;
+/// A named group of file extensions offered by a file dialog
+pub struct FileFilter {
+    pub name: &'static str,
+    pub extensions: &'static [&'static str]
+}
+
+/// A file dialog offering the given filters, then all files, starting in dir if it exists
+fn dialog(filters: &[FileFilter], dir: Option<&Path>) -> FileDialog {
+    let mut dialog = FileDialog::new();
+    for filter in filters {
+        dialog = dialog.add_filter(filter.name, filter.extensions);
+    }
+    if !filters.is_empty() {
+        dialog = dialog.add_filter("All files", &["*"]);
+    }
+    match dir.filter(|d| d.is_dir()) {
+        Some(dir) => dialog.set_directory(dir),
+        None => dialog
+    }
+}
 
 /// Open a file dialog to pick a file
-pub fn open_fd() -> Option<PathBuf> {
-    let f = FileDialog::new()
-        .pick_file();
-    f
+pub fn open_fd(filters: &[FileFilter], dir: Option<&Path>) -> Option<PathBuf> {
+    dialog(filters, dir)
+        .pick_file()
 }
 
-/// Check to see if a file path is valid & exists
-pub fn check_path(path: String) -> bool {
-    let path = PathBuf::from(path);
-    path.try_exists().unwrap()
+/// Open a file dialog to choose where to save a file, suggesting a name
+pub fn save_fd(file_name: &str, filters: &[FileFilter], dir: Option<&Path>) -> Option<PathBuf> {
+    dialog(filters, dir)
+        .set_file_name(file_name)
+        .save_file()
 }
 
-/// Disable all elements in a boolean vec
-pub fn set_all_disabled(bool_vec: &mut Vec<bool>){
-    for b in bool_vec.iter_mut() {
-        *b = false;
+/// Open a file dialog to pick a directory
+pub fn open_dir_fd(dir: Option<&Path>) -> Option<PathBuf> {
+    dialog(&[], dir)
+        .pick_folder()
+}
+
+/// Check to see if a file path is valid & exists, relative paths being taken from the working directory
+pub fn check_path(path: String, working_dir: &str) -> bool {
+    if path.is_empty() {
+        return false;
     }
+    resolve_path(&path, working_dir).try_exists().unwrap_or(false)
 }
 
-/// Enable nth entry in a boolean vec
-pub fn set_enabled(bool_vec: &mut Vec<bool>, indices: Vec<usize>) {
-    for index in indices.iter() {
-        bool_vec[*index] = true;
+/// Resolve a path typed into the form against the run's working directory (the GUI's own if empty)
+pub fn resolve_path(path: &str, working_dir: &str) -> PathBuf {
+    let path = PathBuf::from(path);
+    if path.is_absolute() || working_dir.is_empty() {
+        path
+    } else {
+        Path::new(working_dir).join(path)
     }
 }
 
-/// Handle radio button pressing
-pub fn handle_task_selection(sel_task: &mut gui::Tasks, bool_vec: &mut Vec<bool>, args: &mut Vec<String>) {
-    // Reset enables and CLI arguments
-    set_all_disabled(bool_vec);
-    args.clear();
-
-    match sel_task {
-        gui::Tasks::EnergyCalibration => {set_enabled(bool_vec, vec![3,5]); args.push(String::from("ene"))},
-        gui::Tasks::PlotSpectrum => {set_enabled(bool_vec, vec![3, 6]); args.push(String::from("plo"))},
-        gui::Tasks::CalculatePrimarySpectrum => {set_enabled(bool_vec, vec![0, 6]); args.push(String::from("pri"))},
-        gui::Tasks::CalculateFullSpectrum => {set_enabled(bool_vec, vec![0, 2, 6]); args.push(String::from("calc"))},
-        gui::Tasks::Calibrate => {set_enabled(bool_vec, vec![0, 1, 2, 5]); args.push(String::from("cal"))},
-        gui::Tasks::Quantify => {set_enabled(bool_vec, vec![0, 1, 3, 5, 6]); args.push(String::from("qua"))},
-        gui::Tasks::Evaluate => {set_enabled(bool_vec, vec![0, 1, 2, 4, 5]); args.push(String::from("eva"))},
-        gui::Tasks::Map => {set_enabled(bool_vec, vec![0, 1, 3, 4, 5]); args.push(String::from("map"))},
-        gui::Tasks::CompareMeasuredCalculated => {set_enabled(bool_vec, vec![0, 2, 3, 6]); args.push(String::from("com"))},
-        gui::Tasks::FitOneStandardWithPlot => {set_enabled(bool_vec, vec![0, 2, 5, 6]); args.push(String::from("fits"))}, // NOTE this doesn't show in PIQUANT help
-        gui::Tasks::BulkSumAndMaxValue => {set_enabled(bool_vec, vec![0, 1, 3, 5, 6]); args.push(String::from("sum"))},
-        gui::Tasks::OpticResponse => {set_enabled(bool_vec, vec![0, 2, 3, 5, 6]); args.push(String::from("opt"))},
-        gui::Tasks::None => {set_all_disabled(bool_vec)}
+/// Add a suffix to a file name, before its extension: "map.csv" becomes "map_A.csv"
+pub fn add_suffix(path: &str, suffix: &str) -> String {
+    if path.is_empty() {
+        return String::new();
     }
+    let path = Path::new(path);
+    let stem = path.file_stem().map(|s| s.to_string_lossy()).unwrap_or_default();
+    let name = match path.extension() {
+        Some(extension) => format!("{}{}.{}", stem, suffix, extension.to_string_lossy()),
+        None => format!("{}{}", stem, suffix)
+    };
+    path.with_file_name(name).display().to_string()
 }
 
-        // [0,           1,          2,         3,             4,        5,                6,         7]
-        // [config_file, calib_file, stds_file, spectrum_file, map_file, element_controls, plot_file, execute_button]
-
-/// Set textbox color depending if path is valid or not
-pub fn set_valid_path_colors(text_vec: Vec<String>, color_vec: &mut Vec<Color32>, valid_vec: &mut Vec<bool>) {
-    let mut index = 0;
-    for text in text_vec.iter() {
-        // Check if valid path
-        if check_path(text.to_string()) {
-            // Set background color to green to indicate valid
-            color_vec[index] = Color32::from_rgb(22, 44, 30);
-            valid_vec[index] = true;
-        } else if ((index == 5) | (index == 6)) & text.is_empty() {
-            // If optional arg (plot_file, log_file), it's valid to not supply anything - let's not set color though. 
-            valid_vec[index] = true;
-        } else {
-            color_vec[index] = eframe::egui::Style::default().visuals.extreme_bg_color;
-            valid_vec[index] = false;
+/// Absolute form of the working directory, falling back to the GUI's current directory
+fn working_dir_base(working_dir: &str) -> PathBuf {
+    let cwd = env::current_dir().unwrap_or_default();
+    normalize(&cwd.join(working_dir))
+}
+
+/// Lexically remove "." and ".." components from a path
+fn normalize(path: &Path) -> PathBuf {
+    let mut normalized = PathBuf::new();
+    for component in path.components() {
+        match component {
+            Component::CurDir => {},
+            Component::ParentDir => {normalized.pop();},
+            other => normalized.push(other)
         }
-        index += 1;
     }
+    normalized
+}
+
+/// Rewrite a path so it is absolute
+pub fn make_absolute(path: &str, working_dir: &str) -> String {
+    if path.is_empty() {
+        return String::new();
+    }
+    normalize(&working_dir_base(working_dir).join(path)).display().to_string()
 }
 
-/// Update arguments based on selected task & supplied parameters
-pub fn update_args(sel_task: &mut gui::Tasks, args: &mut Vec<String>, text_vec: Vec<String>) {
-    // Args contains only the initial command now - update arg vec with relevant text boxes
-    let mut indices: Vec<usize> = Vec::new();
-
-    // Match selected task to associated text box indexes
-    match sel_task {
-        gui::Tasks::EnergyCalibration => {indices.extend([3,7])},
-        gui::Tasks::PlotSpectrum => {indices.extend([3,6])},
-        gui::Tasks::CalculatePrimarySpectrum => {indices.extend([0,6])},
-        gui::Tasks::CalculateFullSpectrum => {indices.extend([0,2,6])},
-        gui::Tasks::Calibrate => {indices.extend([0,1,2,7])},
-        gui::Tasks::Quantify => {indices.extend([0,1,3,6,7])},
-        gui::Tasks::Evaluate => {indices.extend([0,1,2,4,7])},
-        gui::Tasks::Map => {indices.extend([0,1,2,3,4,7])},
-        gui::Tasks::CompareMeasuredCalculated => {indices.extend([0,2,3,6])},
-        gui::Tasks::FitOneStandardWithPlot => {indices.extend([0,2,6,7])}, // NOTE this doesn't show in PIQUANT help
-        gui::Tasks::BulkSumAndMaxValue => {indices.extend([0,1,3,6,7])},
-        gui::Tasks::OpticResponse => {indices.extend([0,2,3,6,7])},
-        gui::Tasks::None => {}
-    }
-
-    // Push associated text values to arguments
-    for index in indices.iter() {
-        args.push(text_vec[*index].clone())
+/// Rewrite a path so it is relative to the working directory, where possible
+pub fn make_relative(path: &str, working_dir: &str) -> String {
+    if path.is_empty() {
+        return String::new();
+    }
+    let base = working_dir_base(working_dir);
+    let target = normalize(&base.join(path));
+    let base_parts: Vec<Component> = base.components().collect();
+    let target_parts: Vec<Component> = target.components().collect();
+    let common = base_parts.iter().zip(target_parts.iter()).take_while(|(a, b)| a == b).count();
+
+    // Nothing in common, e.g. a different drive on Windows
+    if common == 0 {
+        return target.display().to_string();
+    }
+    let mut relative = PathBuf::new();
+    for _ in common..base_parts.len() {
+        relative.push("..");
+    }
+    for part in &target_parts[common..] {
+        relative.push(part);
     }
+    if relative.as_os_str().is_empty() {
+        return String::from(".");
+    }
+    relative.display().to_string()
 }
 
-/// Validate if paths are valid for selected indices; primarily used in analyze() command.
-fn check_valid(valid_vec: &mut Vec<bool>, indices: Vec<usize>) -> bool {
-    let mut all_valid: bool = true;
-    for index in indices.iter() {
-        if ! valid_vec[*index] {
-            all_valid = false;
+/// Split text into arguments the way a shell would: whitespace separates them, quotes group them.
+/// A backslash only escapes whitespace, quotes and backslashes, so Windows paths can be typed as they are
+pub fn split_args(text: &str) -> Result<Vec<String>, String> {
+    let mut args = Vec::new();
+    let mut current = String::new();
+    // Whether there is an argument in progress, which may be an empty quoted one
+    let mut started = false;
+    let mut chars = text.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            c if c.is_whitespace() => {
+                if started {
+                    args.push(std::mem::take(&mut current));
+                    started = false;
+                }
+            }
+            '\'' => {
+                started = true;
+                loop {
+                    match chars.next() {
+                        Some('\'') => break,
+                        Some(c) => current.push(c),
+                        None => return Err(String::from("missing closing ' quote"))
+                    }
+                }
+            }
+            '"' => {
+                started = true;
+                loop {
+                    match chars.next() {
+                        Some('"') => break,
+                        Some('\\') if matches!(chars.peek(), Some('"' | '\\')) => current.extend(chars.next()),
+                        Some(c) => current.push(c),
+                        None => return Err(String::from("missing closing \" quote"))
+                    }
+                }
+            }
+            '\\' if chars.peek().is_some_and(|&n| n.is_whitespace() || matches!(n, '"' | '\'' | '\\')) => {
+                started = true;
+                current.extend(chars.next());
+            }
+            c => {
+                started = true;
+                current.push(c);
+            }
         }
     }
-    // Handle no button selected
-    if indices.len() == 0 {
-        all_valid = false;
+    if started {
+        args.push(current);
     }
-    return all_valid;
+    Ok(args)
 }
 
-/// Determine if we should enable the execute button
-pub fn check_ready_to_execute(valid_vec: &mut Vec<bool>, sel_task: &mut gui::Tasks, enable_vec: &mut Vec<bool>) {
-    let mut indices: Vec<usize> = Vec::new();
-    enable_vec[7] = false;
-
-    // Match selected task to associated text box indexes
-    match sel_task {
-        gui::Tasks::EnergyCalibration => {indices.extend([3,5])},
-        gui::Tasks::PlotSpectrum => {indices.extend([3,6])},
-        gui::Tasks::CalculatePrimarySpectrum => {indices.extend([0,6])},
-        gui::Tasks::CalculateFullSpectrum => {indices.extend([0,2,6])},
-        gui::Tasks::Calibrate => {indices.extend([0,1,2,5])},
-        gui::Tasks::Quantify => {indices.extend([0,1,3,5,6])},
-        gui::Tasks::Evaluate => {indices.extend([0,1,2,4,5])},
-        gui::Tasks::Map => {indices.extend([0,1,2,3,4,5])},
-        gui::Tasks::CompareMeasuredCalculated => {indices.extend([0,2,3,6])},
-        gui::Tasks::FitOneStandardWithPlot => {indices.extend([0,2,5,6])}, // NOTE this doesn't show in PIQUANT help
-        gui::Tasks::BulkSumAndMaxValue => {indices.extend([0,1,3,5,6])},
-        gui::Tasks::OpticResponse => {indices.extend([0,2,3,5,6])},
-        gui::Tasks::None => {}
-    }
-
-    // Enable the execute button if relevant fields are valid
-    enable_vec[7] = check_valid(valid_vec, indices);
-}
\ No newline at end of file
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[cfg(unix)]
+    #[test]
+    fn relative_and_absolute_paths() {
+        let cases = [
+            // (path, working directory, relative, absolute)
+            ("/data/run/config.msa", "/data/run", "config.msa", "/data/run/config.msa"),
+            ("/data/calib/std.csv", "/data/run", "../calib/std.csv", "/data/calib/std.csv"),
+            ("/data/run/../calib/./std.csv", "/data/run/", "../calib/std.csv", "/data/calib/std.csv"),
+            ("/other/std.csv", "/data/run", "../../other/std.csv", "/other/std.csv"),
+            ("sub/map.csv", "/data/run", "sub/map.csv", "/data/run/sub/map.csv"),
+            ("../map.csv", "/data/run", "../map.csv", "/data/map.csv"),
+            ("/data/run", "/data/run", ".", "/data/run"),
+            ("", "/data/run", "", "")
+        ];
+        for (path, working_dir, relative, absolute) in cases {
+            assert_eq!(make_relative(path, working_dir), relative, "{} from {}", path, working_dir);
+            assert_eq!(make_absolute(path, working_dir), absolute, "{} from {}", path, working_dir);
+        }
+    }
+
+    #[cfg(windows)]
+    #[test]
+    fn different_drive_stays_absolute() {
+        assert_eq!(make_relative(r"D:\data\std.csv", r"C:\run"), r"D:\data\std.csv");
+    }
+
+    #[test]
+    fn resolving_paths() {
+        assert_eq!(resolve_path("config.msa", "/data/run"), Path::new("/data/run/config.msa"));
+        assert_eq!(resolve_path("config.msa", ""), Path::new("config.msa"));
+        let absolute = env::current_dir().unwrap().join("config.msa");
+        assert_eq!(resolve_path(&absolute.display().to_string(), "/data/run"), absolute);
+        assert_eq!(resolve_path("", "/data/run"), Path::new("/data/run"));
+    }
+
+    #[test]
+    fn normalizing() {
+        assert_eq!(normalize(Path::new("/data/./run/../calib/std.csv")), Path::new("/data/calib/std.csv"));
+        assert_eq!(normalize(Path::new("/data/run/")), Path::new("/data/run"));
+        // Going above the root stays at the root
+        assert_eq!(normalize(Path::new("/data/../../std.csv")), Path::new("/std.csv"));
+        assert_eq!(normalize(Path::new("")), PathBuf::new());
+    }
+}
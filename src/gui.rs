@@ -2,14 +2,47 @@
 // An excellent guide on some egui setup methods is available at https://egui.info/examples/
 
 use log::info;
-use eframe::egui::{self, TextBuffer, Visuals};
+use serde::{Deserialize, Serialize};
+use eframe::egui::{self, Visuals};
 use egui::Color32;
-use std::env;
-use std::process::Command;
+use std::path::PathBuf;
+use std::sync::mpsc::Receiver;
+use std::sync::Arc;
+use std::time::Duration;
+use crate::background;
+use crate::compat;
+use crate::detector;
+use crate::energy_calibration;
+use crate::fit_options;
 use crate::functions;
+use crate::history;
+use crate::map_outputs;
+use crate::plot;
+use crate::progress;
+use crate::queue;
+use crate::runner;
+use crate::schema;
+use crate::script;
+use crate::settings;
+use crate::standards;
+
+// Window title, extended with the PIQUANT version once known
+pub const APP_TITLE: &str = concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
+
+// Prefix marking lines PIQUANT wrote to stderr in the output pane
+const STDERR_MARKER: &str = "[stderr] ";
+
+// Height kept below the form for the command preview, output pane, status line and Execute button
+const OUTPUT_AREA_HEIGHT: f32 = 300.0;
+
+// Only the end of a very long output (e.g. a map) is kept in the output pane
+const MAX_OUTPUT_PANE_BYTES: usize = 1024 * 1024;
+
+// Id of the settings field holding the PIQUANT path, so we can wait until it's been entered
+const PIQUANT_PATH_FIELD: &str = "piquant_path";
 
 // Possible task options
-#[derive(Debug, PartialEq)]
+#[derive(Debug, Clone, Copy, PartialEq, Default, Serialize, Deserialize)]
 pub enum Tasks {
     EnergyCalibration,
     PlotSpectrum,
@@ -23,299 +56,926 @@ pub enum Tasks {
     Quantify,
     BulkSumAndMaxValue,
     Map,
+    ConvertSddData,
+    #[default]
     None
 }
 
+impl Tasks {
+    /// Name shown for the task outside the task selection grid
+    pub fn label(&self) -> &'static str {
+        match self {
+            Tasks::EnergyCalibration => "Energy Calibration",
+            Tasks::PlotSpectrum => "Plot Spectrum",
+            Tasks::CalculatePrimarySpectrum => "Calculate Primary Spectrum",
+            Tasks::CalculateFullSpectrum => "Calculate Full Spectrum",
+            Tasks::CompareMeasuredCalculated => "Compare Measured to Calculated",
+            Tasks::OpticResponse => "Optic Response",
+            Tasks::Calibrate => "Calibrate",
+            Tasks::Evaluate => "Evaluate",
+            Tasks::FitOneStandardWithPlot => "Fit one standard with plot",
+            Tasks::Quantify => "Quantify",
+            Tasks::BulkSumAndMaxValue => "Bulk sum and max value",
+            Tasks::Map => "Map",
+            Tasks::ConvertSddData => "Convert SDD data (ems)",
+            Tasks::None => "None"
+        }
+    }
+}
+
 pub struct PiquantApp {
-    task_sel: Tasks,
-    config_file: String,
-    calib_file: String,
-    standards_file: String,
-    spectrum_file: String,
-    map_file: String,
-    plot_file: String,
-    log_file: String,
-    element_controls: String,
-    cli_args: String,
+    form: schema::Form,
     output_text: String,
-    enable_vec: Vec<bool>,
-    color_vec: Vec<Color32>,
-    valid_vec: Vec<bool>,
-    args: Vec<String>
+    // Laid out output text, with the text length and wrap width it was laid out for; output is only
+    // ever added to, so the layout only needs redoing when the length changes
+    output_galley: Option<(usize, f32, Arc<egui::Galley>)>,
+    args: Vec<String>,
+    job: Option<runner::Job>,
+    job_state: runner::JobState,
+    exit_code: Option<i32>,
+    error_banners: Vec<String>,
+    settings: settings::Settings,
+    show_settings: bool,
+    piquant_arg: Option<String>,
+    piquant_exe: Option<(PathBuf, runner::PathSource)>,
+    piquant_exe_for: Option<String>,
+    piquant_version: Option<compat::Version>,
+    version_text: String,
+    version_query: Option<Receiver<Result<String, String>>>,
+    queue: queue::JobQueue,
+    show_queue: bool,
+    history: history::History,
+    // Index of the history entry for the running job
+    active_history: Option<usize>,
+    show_history: bool,
+    selected_history: Option<usize>,
+    progress: Option<progress::Progress>,
+    // Name typed in for saving the map column selection as a preset
+    preset_name: String,
+    // Standards read from the standards input file, for the -s picker
+    standards_list: Option<standards::StandardsList>,
+    // A run waiting for the user to agree to replace the result files listed
+    overwrite: Option<(PendingRun, Vec<PathBuf>)>,
+    // Plot file open in the plot window
+    plot: Option<plot::PlotViewer>,
+    show_plot: bool
 }
 
 /// Set up the app with initial values
 impl PiquantApp {
     // Initial application setup
-    pub fn new(_cc: &eframe::CreationContext<'_>) -> Self {
-        // Get default style - may be dark or light depending on user theme
-        let default_bg = egui::Style::default().visuals.extreme_bg_color;
-
+    pub fn new(cc: &eframe::CreationContext<'_>, piquant_arg: Option<String>) -> Self {
         // Provide initial values
         Self{
-            task_sel: Tasks::None,
-            config_file: String::new(),
-            calib_file: String::new(),
-            standards_file: String::new(),
-            spectrum_file: String::new(),
-            map_file: String::new(),
-            plot_file: String::new(),
-            log_file: String::new(),
-            element_controls: String::new(),
-            cli_args: String::new(),
+            form: schema::Form::default(),
             output_text: String::new(),
-            enable_vec: vec![false, false, false, false, false, false, false, false],
-            color_vec: vec![default_bg, default_bg, default_bg, default_bg, default_bg, default_bg, default_bg, default_bg, default_bg],
-            valid_vec: vec![false, false, false, false, false, false, false, false, false],
-            args: Vec::new()
+            output_galley: None,
+            args: Vec::new(),
+            job: None,
+            job_state: runner::JobState::Idle,
+            exit_code: None,
+            error_banners: Vec::new(),
+            settings: settings::load(cc.storage),
+            show_settings: false,
+            piquant_arg,
+            piquant_exe: None,
+            piquant_exe_for: None,
+            piquant_version: None,
+            version_text: String::new(),
+            version_query: None,
+            queue: queue::JobQueue::default(),
+            show_queue: false,
+            history: history::load(cc.storage),
+            active_history: None,
+            show_history: false,
+            selected_history: None,
+            progress: None,
+            preset_name: String::new(),
+            standards_list: None,
+            overwrite: None,
+            plot: None,
+            show_plot: false
+        }
+    }
+
+    /// Append any PIQUANT output that arrived since the last frame and note when the job ends
+    fn poll_job(&mut self, ctx: &egui::Context) {
+        if let Some(running) = &self.job {
+            for event in running.poll() {
+                match event {
+                    runner::JobEvent::Stdout(line) => {
+                        if let Some(progress) = &mut self.progress {
+                            progress.update(&line);
+                        }
+                        self.output_text.push_str(&line);
+                        self.output_text.push('\n');
+                    }
+                    runner::JobEvent::Stderr(line) => {
+                        self.output_text.push_str(STDERR_MARKER);
+                        self.output_text.push_str(&line);
+                        self.output_text.push('\n');
+                    }
+                    runner::JobEvent::InvalidUtf8(stream) => {
+                        self.error_banners.push(format!("PIQUANT wrote invalid UTF-8 to {}; unreadable characters were replaced", stream));
+                    }
+                    runner::JobEvent::Exited(Ok(status)) if running.is_cancelled() => {
+                        self.output_text.push_str("Run cancelled by user.\n");
+                        self.exit_code = status.code();
+                        self.job_state = runner::JobState::Cancelled;
+                    }
+                    runner::JobEvent::Exited(Ok(status)) => {
+                        self.exit_code = status.code();
+                        if let Some(failure) = runner::describe_failure(&status) {
+                            self.output_text.push_str(format!("{}\n", failure).as_str());
+                            self.error_banners.push(failure);
+                            self.job_state = runner::JobState::Failed;
+                        } else {
+                            self.job_state = runner::JobState::Finished;
+                        }
+                    }
+                    runner::JobEvent::Exited(Err(e)) => {
+                        let failure = format!("Failed to run PIQUANT: {}", e);
+                        self.output_text.push_str(format!("{}\n", failure).as_str());
+                        self.error_banners.push(failure);
+                        self.job_state = runner::JobState::Failed;
+                    }
+                }
+            }
+            if self.output_text.len() > MAX_OUTPUT_PANE_BYTES {
+                // Cut at the start of a line, so stderr lines keep their marker
+                let over = self.output_text.len() - MAX_OUTPUT_PANE_BYTES;
+                let cut = self.output_text.as_bytes()[over..].iter()
+                    .position(|&b| b == b'\n')
+                    .map_or(self.output_text.len(), |i| over + i + 1);
+                self.output_text.drain(..cut);
+                self.output_galley = None;
+            }
+            if self.job_state == runner::JobState::Running {
+                // Keep repainting so new output, the spinner and elapsed time stay current
+                ctx.request_repaint_after(Duration::from_millis(100));
+            } else {
+                self.job = None;
+                self.queue.finish_active(self.job_state, self.exit_code);
+                if let Some(index) = self.active_history.take() {
+                    self.history.finish(index, self.job_state, self.exit_code, &self.output_text);
+
+                    // Show the plot file a successful run wrote
+                    let written = self.history.entries.get(index).and_then(|entry| schema::plot_output(&entry.form));
+                    if let Some(path) = written.filter(|p| self.job_state == runner::JobState::Finished && p.is_file()) {
+                        let element_list = self.history.entries[index].form.element_controls.clone();
+                        self.plot = Some(plot::PlotViewer::open(&path, &element_list));
+                        self.show_plot = true;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Start or queue a run the user asked for
+    fn carry_out(&mut self, run: PendingRun) {
+        match run {
+            PendingRun::Execute => {
+                // A form standing for several runs goes through the queue
+                let mut runs = schema::runs(&self.form);
+                if runs.len() == 1 {
+                    let form = runs.remove(0);
+                    self.start_job(schema::args(&form), form);
+                } else {
+                    // Only these runs are started; anything already waiting in the queue stays there
+                    let ids = runs.into_iter().map(|run| {
+                        let args = schema::args(&run);
+                        self.queue.push(run, args)
+                    }).collect();
+                    self.queue.run_only(ids);
+                }
+            }
+            PendingRun::Queue => {
+                for run in schema::runs(&self.form) {
+                    let args = schema::args(&run);
+                    self.queue.push(run, args);
+                }
+            }
+            PendingRun::Rerun(args, form) => self.start_job(args, *form),
+            PendingRun::Retry(id) => {
+                if let Some(index) = self.queue.jobs.iter().position(|j| j.id == id) {
+                    self.queue.retry(index);
+                }
+            }
+        }
+    }
+
+    /// Start PIQUANT with the given arguments, replacing the output of the previous run
+    fn start_job(&mut self, args: Vec<String>, form: schema::Form) {
+        // Clear output text and errors from the previous run
+        self.output_text.clear();
+        self.output_galley = None;
+        self.error_banners.clear();
+        self.exit_code = None;
+        let command = script::command_line(&program_name(&self.piquant_exe), &args, script::Shell::native());
+        self.output_text.push_str(format!("Command: {}\n", command).as_str());
+
+        // Map and Bulk Sum runs can show how many spectra are done
+        let spectrum_path = functions::resolve_path(&form.spectrum_file, &form.working_dir);
+        self.progress = progress::Progress::new(form.task, &spectrum_path, &args);
+        let working_dir = Some(PathBuf::from(&form.working_dir)).filter(|d| !d.as_os_str().is_empty());
+        // PIQUANT writes nothing to the terminal when given a log file, so the log is followed instead
+        let log_file = schema::log_output(&form);
+
+        // Send arguments to PIQUANT on a worker thread, so the window stays responsive
+        if self.history.is_full() {
+            // The oldest entry is dropped, moving the rest down; forget it if it was the one selected
+            self.selected_history = self.selected_history.and_then(|i| i.checked_sub(1));
+        }
+        let history_index = self.history.start(form, args.clone());
+        match &self.piquant_exe {
+            Some((exe, _)) => {
+                self.job = Some(runner::Job::spawn(exe.clone(), args, working_dir, log_file));
+                self.job_state = runner::JobState::Running;
+                self.active_history = Some(history_index);
+            }
+            None => {
+                self.error_banners.push(String::from("Could not locate PIQUANT; set its path in Settings"));
+                self.job_state = runner::JobState::Failed;
+                self.queue.finish_active(self.job_state, None);
+                self.history.finish(history_index, self.job_state, None, &self.output_text);
+            }
         }
-        // Regarding enable vec:
-        // [0,           1,          2,         3,             4,        5,                6,         7]
-        // [config_file, calib_file, stds_file, spectrum_file, map_file, element_controls, plot_file, execute_button]
-
-        // Color vec:
-        // [0,           1,          2,         3,             4,        5,         6]
-        // [config_file, calib_file, stds_file, spectrum_file, map_file, plot_file, log_file]
-
-        // Text vec:
-        // [0,           1,          2,         3,             4,        5,         6,        7,                8]
-        // [config_file, calib_file, stds_file, spectrum_file, map_file, plot_file, log_file, element_controls, cli_args]
-        // plot_file & log_file are optional (5, 6), ditto cli_args
     }
 }
 impl eframe::App for PiquantApp {
+    /// Called by eframe to persist settings between sessions
+    fn save(&mut self, storage: &mut dyn eframe::Storage) {
+        settings::save(storage, &self.settings);
+        history::save(storage, &self.history);
+    }
+
     /// Called each time the UI needs repainting, which may be many times per second.
     fn update(&mut self, ctx: &egui::Context, _frame: &mut eframe::Frame) {
+        // Pick up output from a running job, then start the next queued job once nothing is running
+        self.poll_job(ctx);
+        if self.job.is_none() {
+            if let Some((args, form)) = self.queue.start_next() {
+                self.start_job(args, form);
+            }
+        }
+
         let Self { 
-            task_sel,
-            config_file,
-            calib_file,
-            standards_file,
-            spectrum_file,
-            map_file,
-            plot_file,
-            log_file,
-            element_controls,
-            cli_args,
+            form,
             output_text,
-            enable_vec,
-            color_vec,
-            valid_vec,
-            args
+            output_galley,
+            args,
+            job,
+            job_state,
+            exit_code,
+            error_banners,
+            settings,
+            show_settings,
+            piquant_arg,
+            piquant_exe,
+            piquant_exe_for,
+            piquant_version,
+            version_text,
+            version_query,
+            queue,
+            show_queue,
+            history,
+            active_history: _,
+            show_history,
+            selected_history,
+            progress,
+            preset_name,
+            standards_list,
+            overwrite,
+            plot,
+            show_plot
         } = self;
 
         // -------- Functions to run per app update
 
-        // Update text_vec to values of each file field
-        let text_vec: Vec<String> = vec![config_file.clone(), calib_file.clone(), standards_file.clone(), spectrum_file.clone(), map_file.clone(), plot_file.clone(), log_file.clone(), element_controls.clone(), cli_args.clone()];
+        // Fields, checks and arguments all follow the selected task
+        let task_spec = schema::task_spec(form.task);
+        *args = schema::args(form);
+        let option_errors = schema::option_errors(form);
 
-        // Update enables to match selected tasks
-        functions::handle_task_selection(task_sel, enable_vec, args);
+        // A working directory that doesn't exist blocks the run
+        let working_dir_valid = form.working_dir.is_empty() || PathBuf::from(form.working_dir.as_str()).is_dir();
+        let ready = working_dir_valid && task_spec.is_some_and(|spec| spec.ready(form));
 
-        // Check each path for validity. Ask Tim re: colors. 
-        functions::set_valid_path_colors(text_vec.clone(), color_vec, valid_vec);
+        // Read the standards file again whenever it changes, for the standard picker
+        if task_spec.is_some_and(|spec| spec.accepts("-s")) && !form.standards_file.is_empty() {
+            let path = functions::resolve_path(&form.standards_file, &form.working_dir);
+            standards::StandardsList::load(standards_list, &path);
+        }
 
-        // Update args vec based on current selection and parameters
-        functions::update_args(task_sel, args, text_vec);
+        // Find the PIQUANT executable again once a changed path has been entered, not on every keystroke
+        let editing_path = ctx.memory(|m| m.has_focus(egui::Id::new(PIQUANT_PATH_FIELD)));
+        if !editing_path && piquant_exe_for.as_ref() != Some(&settings.piquant_path) {
+            *piquant_exe = match runner::find_piquant(piquant_arg.as_deref(), &settings.piquant_path) {
+                Ok(found) => Some(found),
+                Err(e) => {
+                    let message = format!("Could not locate PIQUANT: {}", e);
+                    if !error_banners.contains(&message) {
+                        error_banners.push(message);
+                    }
+                    None
+                }
+            };
+            *piquant_exe_for = Some(settings.piquant_path.clone());
 
-        // Check if execute button is ready to go
-        functions::check_ready_to_execute(valid_vec, task_sel, enable_vec);
+            // Ask the new executable for its version
+            *piquant_version = None;
+            if let Some((exe, _)) = piquant_exe {
+                *version_text = String::from("checking...");
+                *version_query = Some(runner::query_version(exe.clone()));
+            } else {
+                *version_text = String::from("not found");
+                *version_query = None;
+            }
+            ctx.send_viewport_cmd(egui::ViewportCommand::Title(APP_TITLE.to_string()));
+        }
 
-        // ---------- UI building section 
+        // Pick up the version once PIQUANT has answered
+        if let Some(query) = version_query {
+            if let Ok(result) = query.try_recv() {
+                match result {
+                    Ok(text) => {
+                        *piquant_version = compat::Version::parse(&text);
+                        ctx.send_viewport_cmd(egui::ViewportCommand::Title(format!("{} - PIQUANT {}", APP_TITLE, text)));
+                        *version_text = text;
+                    }
+                    Err(e) => *version_text = format!("unknown ({})", e)
+                }
+                *version_query = None;
+            } else {
+                ctx.request_repaint_after(Duration::from_millis(100));
+            }
+        }
 
-        // Create a central panel to hold our widgets in the window
-        egui::CentralPanel::default().show(ctx, |ui| {
+        // Warn about sub-commands and options this PIQUANT doesn't support, and CLI arguments repeating the form's options
+        let mut warnings = compat::check(*piquant_version, args);
+        for flag in schema::cli_clashes(form) {
+            warnings.push(format!("{} in the CLI arguments clashes with the form's own setting; being given last, the CLI value wins", flag));
+        }
 
-            // Use dark theme by default
-            ui.style_mut().visuals = Visuals::dark();
+        let running = *job_state == runner::JobState::Running;
 
-            // Task selection section
-            ui.heading("Task selection");
-            ui.add_space(10.0);
+        // Actions that need the whole app; carried out once the UI is built
+        let mut execute = false;
+        let mut rerun: Option<(Vec<String>, schema::Form)> = None;
+        let mut restore: Option<schema::Form> = None;
+        let mut add_to_queue = false;
+        let mut retry: Option<u64> = None;
 
-            // Grid to contain task list (see Tasks enum)
-            egui::Grid::new("task_selection")
-                .striped(true)
-                .spacing([25.0, 10.0])
-                .show(ui, |ui| {
-                    ui.radio_value(task_sel, Tasks::EnergyCalibration, "Energy Calibration");
-                    ui.radio_value(task_sel, Tasks::PlotSpectrum, "Plot Spectrum");
-                    ui.radio_value(task_sel, Tasks::CalculatePrimarySpectrum, "Calculate Primary Spectrum");
-                    ui.radio_value(task_sel, Tasks::CalculateFullSpectrum, "Calculate Full Spectrum");
-                    ui.end_row();
-                
-                    ui.radio_value(task_sel, Tasks::CompareMeasuredCalculated, "Compare Measured to Calculated");
-                    ui.radio_value(task_sel, Tasks::OpticResponse, "Optic Response");
-                    ui.radio_value(task_sel, Tasks::Calibrate, "Calibrate");
-                    ui.radio_value(task_sel, Tasks::Evaluate, "Evaluate");
-                    ui.end_row();
-                
-                    ui.radio_value(task_sel, Tasks::FitOneStandardWithPlot, "Fit one standard with plot");
-                    ui.radio_value(task_sel, Tasks::Quantify, "Quantify");
-                    ui.radio_value(task_sel, Tasks::BulkSumAndMaxValue, "Bulk sum and max value");
-                    ui.radio_value(task_sel, Tasks::Map, "Map");
-                    ui.end_row();
-                }
-            );
-            info!("Created grid");
+        // ---------- UI building section 
 
-            // Separator
-            ui.add_space(5.0);
-            ui.separator();
-            ui.add_space(5.0);
+        // Menu bar
+        egui::TopBottomPanel::top("menu_bar").show(ctx, |ui| {
+            egui::menu::bar(ui, |ui| {
+                if ui.button("Settings").clicked() {
+                    *show_settings = !*show_settings;
+                }
+                if ui.button(format!("Queue ({})", queue.jobs.len())).clicked() {
+                    *show_queue = !*show_queue;
+                }
+                if ui.button("History").clicked() {
+                    *show_history = !*show_history;
+                }
+                if ui.button("Plot").clicked() {
+                    *show_plot = !*show_plot;
+                }
+            });
+        });
 
-            // Configuration section
-            ui.heading("Configuration");
-            ui.add_space(10.0);
-            
-            ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
-                ui.add_space(125.0);
-                egui::Grid::new("configuration")
-                    .spacing([50.0, 12.5])
+        // Settings window
+        egui::Window::new("Settings")
+            .open(show_settings)
+            .resizable(false)
+            .show(ctx, |ui| {
+                egui::Grid::new("settings")
+                    .spacing([20.0, 10.0])
                     .num_columns(2)
                     .show(ui, |ui| {
-                        // Config file
-                        ui.add(egui::Label::new("Configuration file"));
+                        ui.add(egui::Label::new("PIQUANT executable"));
                         ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[0], egui::TextEdit::singleline(config_file)
-                                .hint_text("path to configuration file")
-                                .background_color(color_vec[0]));
-                            if ui.add_enabled(enable_vec[0], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
+                            ui.add(egui::TextEdit::singleline(&mut settings.piquant_path)
+                                .id(egui::Id::new(PIQUANT_PATH_FIELD))
+                                .hint_text("leave empty to search PATH"));
+                            if ui.button("Browse").clicked() {
+                                let f = browse(settings, "PIQUANT executable", &[], "", None);
                                 if let Some(path) = f {
-                                    *config_file =  path.into_os_string().into_string().unwrap();
+                                    settings.piquant_path = path.display().to_string();
                                 }
                             };
                         });
                         ui.end_row();
 
-                        // Calibration file
-                        ui.add( egui::Label::new("Calibration file"));
-                        ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[1], egui::TextEdit::singleline(calib_file)
-                                .hint_text("path to calibration file")
-                                .background_color(color_vec[1]));
-                            if ui.add_enabled(enable_vec[1], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *calib_file =  path.into_os_string().into_string().unwrap();
-                                }
-                            };
-                        });
+                        ui.add(egui::Label::new("In use"));
+                        match piquant_exe {
+                            Some((path, source)) => {
+                                ui.vertical(|ui| {
+                                    ui.label(path.display().to_string());
+                                    ui.horizontal(|ui| {
+                                        ui.weak(format!("from {}", source.label()));
+                                        if runner::is_executable(path) {
+                                            ui.colored_label(Color32::LIGHT_GREEN, "executable");
+                                        } else {
+                                            ui.colored_label(Color32::LIGHT_RED, "not executable");
+                                        }
+                                    });
+                                });
+                            }
+                            None => {ui.colored_label(Color32::LIGHT_RED, "not found");}
+                        }
                         ui.end_row();
 
-                        // Standards file
-                        ui.add(egui::Label::new("Standards input file"));
-                        ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[2], egui::TextEdit::singleline(standards_file)
-                                .hint_text("path to standards input file")
-                                .background_color(color_vec[2]));
-                            if ui.add_enabled(enable_vec[2], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *standards_file =  path.into_os_string().into_string().unwrap();
-                                }
-                            };
-                        });
+                        ui.add(egui::Label::new("Version"));
+                        ui.label(version_text.as_str());
                         ui.end_row();
+                    });
+            });
 
-                        // Spectrum file
-                        ui.add(egui::Label::new("Spectrum file"));
-                        ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[3], egui::TextEdit::singleline(spectrum_file)
-                                .hint_text("path to spectrum file")
-                                .background_color(color_vec[3]));
-                            if ui.add_enabled(enable_vec[3], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *spectrum_file =  path.into_os_string().into_string().unwrap();
+        // Queue window
+        egui::Window::new("Queue")
+            .open(show_queue)
+            .default_width(500.0)
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if queue.running {
+                        if ui.button("Stop after current job").clicked() {
+                            queue.running = false;
+                            queue.only = None;
+                        }
+                    } else if ui.add_enabled(!queue.jobs.is_empty(), egui::Button::new("Run queue")).clicked() {
+                        queue.run_all();
+                    }
+                    if ui.button("Clear finished").clicked() {
+                        queue.clear_finished();
+                    }
+                    if ui.add_enabled(!queue.jobs.is_empty(), egui::Button::new("Export as script")).clicked() {
+                        let runs: Vec<(&str, &[String])> = queue.jobs.iter().map(|j| (j.form.working_dir.as_str(), j.args.as_slice())).collect();
+                        if let Err(e) = export_script(settings, &program_name(piquant_exe), &runs) {
+                            error_banners.push(e);
+                        }
+                    }
+                });
+                ui.separator();
+
+                if queue.jobs.is_empty() {
+                    ui.weak("Use \"Add to queue\" to queue the current task and its arguments.");
+                }
+                let mut action: Option<(usize, QueueAction)> = None;
+                egui::ScrollArea::vertical().max_height(300.0).show(ui, |ui| {
+                    egui::Grid::new("queue").striped(true).num_columns(3).show(ui, |ui| {
+                        for (index, queued) in queue.jobs.iter().enumerate() {
+                            let active = queue.active == Some(queued.id);
+                            ui.label(format!("{}. {}", index + 1, queued.form.task.label()))
+                                .on_hover_text(queued.args.join(" "));
+                            match queued.state {
+                                runner::JobState::Idle => {ui.label("queued");},
+                                runner::JobState::Running => {ui.horizontal(|ui| {ui.spinner(); ui.label("running");});},
+                                runner::JobState::Finished => {ui.colored_label(Color32::LIGHT_GREEN, "finished");},
+                                runner::JobState::Failed => {
+                                    match queued.exit_code {
+                                        Some(code) => ui.colored_label(Color32::LIGHT_RED, format!("failed ({})", code)),
+                                        None => ui.colored_label(Color32::LIGHT_RED, "failed")
+                                    };
+                                },
+                                runner::JobState::Cancelled => {ui.colored_label(Color32::YELLOW, "cancelled");}
+                            }
+                            ui.horizontal(|ui| {
+                                if ui.small_button("\u{2B06}").on_hover_text("Move up").clicked() {
+                                    action = Some((index, QueueAction::MoveUp));
                                 }
-                            };
-                        });
-                        ui.end_row();
+                                if ui.small_button("\u{2B07}").on_hover_text("Move down").clicked() {
+                                    action = Some((index, QueueAction::MoveDown));
+                                }
+                                let done = !matches!(queued.state, runner::JobState::Idle | runner::JobState::Running);
+                                if ui.add_enabled(done, egui::Button::new("Retry").small()).clicked() {
+                                    action = Some((index, QueueAction::Retry));
+                                }
+                                if ui.add_enabled(!active, egui::Button::new("Remove").small()).clicked() {
+                                    action = Some((index, QueueAction::Remove));
+                                }
+                            });
+                            ui.end_row();
+                        }
+                    });
+                });
+                match action {
+                    Some((index, QueueAction::MoveUp)) => queue.move_up(index),
+                    Some((index, QueueAction::MoveDown)) => queue.move_down(index),
+                    Some((index, QueueAction::Retry)) => retry = queue.jobs.get(index).map(|j| j.id),
+                    Some((index, QueueAction::Remove)) => queue.remove(index),
+                    None => {}
+                }
+            });
 
-                        // Map file
-                        ui.add(egui::Label::new("Map file"));
-                        ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[4], egui::TextEdit::singleline(map_file)
-                                .hint_text("path to map file (optional?)")
-                                .background_color(color_vec[4]));
-                            if ui.add_enabled(enable_vec[4], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *map_file =  path.into_os_string().into_string().unwrap();
+        // Confirmation before a run replaces results from an earlier one
+        let mut confirmed: Option<PendingRun> = None;
+        if let Some((_, files)) = overwrite.as_ref() {
+            let mut replace = false;
+            let mut cancel = false;
+            let modal = egui::Modal::new(egui::Id::new("overwrite")).show(ctx, |ui| {
+                ui.heading("Replace existing files?");
+                ui.label("PIQUANT will overwrite these results:");
+                for file in files {
+                    ui.monospace(file.display().to_string());
+                }
+                ui.add_space(10.0);
+                ui.horizontal(|ui| {
+                    replace = ui.button("Replace").clicked();
+                    cancel = ui.button("Cancel").clicked();
+                });
+            });
+            if replace {
+                confirmed = overwrite.take().map(|(run, _)| run);
+            } else if cancel || modal.should_close() {
+                *overwrite = None;
+            }
+        }
+
+        // Plot window
+        egui::Window::new("Plot")
+            .open(show_plot)
+            .default_size([760.0, 480.0])
+            .show(ctx, |ui| {
+                ui.horizontal(|ui| {
+                    if ui.button("Open plot file").clicked() {
+                        if let Some(path) = browse(settings, "Plot viewer", schema::Field::Plot.filters(), &form.working_dir, None) {
+                            *plot = Some(plot::PlotViewer::open(&path, &form.element_controls));
+                        }
+                    }
+                    if let Some(viewer) = plot.as_mut() {
+                        ui.label(file_name(&viewer.path.to_string_lossy()));
+                        if ui.button("Reload").clicked() {
+                            viewer.reload();
+                        }
+                    }
+                });
+                match plot.as_mut() {
+                    Some(viewer) => plot::show(ui, viewer),
+                    None => {ui.weak("No plot open. Plots open here after runs that write a .csv plot file.");}
+                }
+            });
+
+        // History window
+        egui::Window::new("History")
+            .open(show_history)
+            .default_width(700.0)
+            .show(ctx, |ui| {
+                if history.entries.is_empty() {
+                    ui.weak("No runs yet.");
+                }
+                ui.horizontal_top(|ui| {
+                    // Newest runs first
+                    egui::ScrollArea::vertical().id_salt("history_list").max_height(350.0).show(ui, |ui| {
+                        ui.vertical(|ui| {
+                            for (index, entry) in history.entries.iter().enumerate().rev() {
+                                let text = format!("{}  {}", history::format_time(entry.started), entry.form.task.label());
+                                let text = match entry.state {
+                                    runner::JobState::Failed => egui::RichText::new(text).color(Color32::LIGHT_RED),
+                                    runner::JobState::Cancelled => egui::RichText::new(text).color(Color32::YELLOW),
+                                    _ => egui::RichText::new(text)
+                                };
+                                if ui.selectable_label(*selected_history == Some(index), text).clicked() {
+                                    *selected_history = Some(index);
                                 }
+                            }
+                        });
+                    });
+                    ui.separator();
+
+                    let Some(entry) = selected_history.and_then(|i| history.entries.get(i)) else {
+                        ui.weak("Select a run to see its output.");
+                        return;
+                    };
+                    ui.vertical(|ui| {
+                        egui::Grid::new("history_details").num_columns(2).show(ui, |ui| {
+                            ui.label("Task");
+                            ui.label(entry.form.task.label());
+                            ui.end_row();
+                            ui.label("Arguments");
+                            ui.label(entry.args.join(" "));
+                            ui.end_row();
+                            ui.label("Started");
+                            ui.label(history::format_time(entry.started));
+                            ui.end_row();
+                            ui.label("Ended");
+                            ui.label(entry.ended.map(history::format_time).unwrap_or_default());
+                            ui.end_row();
+                            ui.label("Status");
+                            match entry.exit_code {
+                                Some(code) => ui.label(format!("{:?} (exit code {})", entry.state, code)),
+                                None => ui.label(format!("{:?}", entry.state))
                             };
+                            ui.end_row();
                         });
-                        ui.end_row();
+                        ui.horizontal(|ui| {
+                            if ui.add_enabled(!running, egui::Button::new("Restore these settings into the form")).clicked() {
+                                restore = Some(entry.form.clone());
+                            }
+                            if ui.add_enabled(!running, egui::Button::new("Run again")).clicked() {
+                                rerun = Some((entry.args.clone(), entry.form.clone()));
+                            }
+                        });
+                        egui::ScrollArea::vertical().id_salt("history_output").max_height(250.0).show(ui, |ui| {
+                            let mut output = entry.output.as_str();
+                            ui.add(egui::TextEdit::multiline(&mut output)
+                                .font(egui::TextStyle::Monospace)
+                                .desired_width(f32::INFINITY));
+                        });
+                    });
+                });
+            });
 
-                        // Element fit controls
-                        ui.add(egui::Label::new("Element fit controls"));
-                        ui.add_enabled(enable_vec[5], egui::TextEdit::singleline(element_controls).hint_text("FE_[KLMN] [IFX]").desired_width(340.0));
-                        ui.end_row();
-                    }
-                );
+        // Show errors from the last run above the form until dismissed
+        if !error_banners.is_empty() {
+            egui::TopBottomPanel::top("error_banners").show(ctx, |ui| {
+                let mut dismissed = None;
+                for (index, message) in error_banners.iter().enumerate() {
+                    egui::Frame::new()
+                        .fill(Color32::from_rgb(70, 20, 20))
+                        .inner_margin(6.0)
+                        .show(ui, |ui| {
+                            ui.horizontal(|ui| {
+                                ui.colored_label(Color32::LIGHT_RED, message);
+                                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                                    if ui.small_button("x").clicked() {
+                                        dismissed = Some(index);
+                                    }
+                                });
+                            });
+                        });
+                }
+                if let Some(index) = dismissed {
+                    error_banners.remove(index);
+                }
             });
+        }
 
-            // Separator
-            ui.add_space(5.0);
-            ui.separator();
-            ui.add_space(5.0);
+        // Create a central panel to hold our widgets in the window
+        egui::CentralPanel::default().show(ctx, |ui| {
 
-            // Optional arguments section
-            ui.heading("Optional arguments");
-            ui.add_space(10.0);
+            // Use dark theme by default
+            ui.style_mut().visuals = Visuals::dark();
 
-            ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
-                ui.add_space(125.0);
+            // Lock the form while PIQUANT is running; it scrolls once it no longer fits above the output
+            egui::ScrollArea::vertical()
+                .id_salt("form")
+                .auto_shrink([false, true])
+                .max_height(ui.available_height() - OUTPUT_AREA_HEIGHT)
+                .show(ui, |ui| {
+                ui.add_enabled_ui(!running, |ui| {
+                    // Task selection section
+                    ui.heading("Task selection");
+                    ui.add_space(10.0);
 
-                egui::Grid::new("optional_arguments")
-                    .spacing([50.0, 15.0])
-                    .num_columns(2)
-                    .show(ui, |ui| {
-                        // Plot file
-                        ui.add(egui::Label::new("Plot file"));
-                        ui.horizontal(|ui| {
-                            ui.add_enabled(enable_vec[6], egui::TextEdit::singleline(plot_file)
-                                .hint_text("path to plot file (optional)")
-                                .background_color(color_vec[5]));
-                            if ui.add_enabled(enable_vec[6], egui::Button::new("Browse")).clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *plot_file =  path.into_os_string().into_string().unwrap();
+                    // Grid to contain task list (see Tasks enum)
+                    egui::Grid::new("task_selection")
+                        .striped(true)
+                        .spacing([25.0, 10.0])
+                        .show(ui, |ui| {
+                            ui.radio_value(&mut form.task, Tasks::EnergyCalibration, "Energy Calibration");
+                            ui.radio_value(&mut form.task, Tasks::PlotSpectrum, "Plot Spectrum");
+                            ui.radio_value(&mut form.task, Tasks::CalculatePrimarySpectrum, "Calculate Primary Spectrum");
+                            ui.radio_value(&mut form.task, Tasks::CalculateFullSpectrum, "Calculate Full Spectrum");
+                            ui.end_row();
+                    
+                            ui.radio_value(&mut form.task, Tasks::CompareMeasuredCalculated, "Compare Measured to Calculated");
+                            ui.radio_value(&mut form.task, Tasks::OpticResponse, "Optic Response");
+                            ui.radio_value(&mut form.task, Tasks::Calibrate, "Calibrate");
+                            ui.radio_value(&mut form.task, Tasks::Evaluate, "Evaluate");
+                            ui.end_row();
+                    
+                            ui.radio_value(&mut form.task, Tasks::FitOneStandardWithPlot, "Fit one standard with plot");
+                            ui.radio_value(&mut form.task, Tasks::Quantify, "Quantify");
+                            ui.radio_value(&mut form.task, Tasks::BulkSumAndMaxValue, "Bulk sum and max value");
+                            ui.radio_value(&mut form.task, Tasks::Map, "Map");
+                            ui.end_row();
+
+                            ui.radio_value(&mut form.task, Tasks::ConvertSddData, "Convert SDD data (ems)");
+                            ui.end_row();
+                        }
+                    );
+                    info!("Created grid");
+
+                    // Separator
+                    ui.add_space(5.0);
+                    ui.separator();
+                    ui.add_space(5.0);
+
+                    // Configuration section
+                    ui.heading("Configuration");
+                    ui.add_space(10.0);
+                
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                        ui.add_space(125.0);
+                        egui::Grid::new("configuration")
+                            .spacing([50.0, 12.5])
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                // Working directory for the run; relative paths below are taken from here
+                                ui.add(egui::Label::new("Working directory"));
+                                ui.horizontal(|ui| {
+                                    let background = if form.working_dir.is_empty() {
+                                        eframe::egui::Style::default().visuals.extreme_bg_color
+                                    } else if working_dir_valid {
+                                        Color32::from_rgb(22, 44, 30)
+                                    } else {
+                                        Color32::from_rgb(70, 20, 20)
+                                    };
+                                    ui.add(egui::TextEdit::singleline(&mut form.working_dir)
+                                        .hint_text("directory to run PIQUANT in (optional)")
+                                        .background_color(background));
+                                    if ui.button("Browse").clicked() {
+                                        let f = functions::open_dir_fd(settings.last_dir("working directory").as_deref());
+                                        if let Some(path) = f {
+                                            settings.remember_dir("working directory", &path);
+                                            form.working_dir = path.display().to_string();
+                                        }
+                                    };
+                                });
+                                ui.end_row();
+
+                                ui.label("");
+                                ui.horizontal(|ui| {
+                                    let relative = ui.button("Make paths relative").clicked();
+                                    let absolute = ui.button("Make paths absolute").clicked();
+                                    let working_dir = form.working_dir.clone();
+                                    let rewrite = |path: &mut String| {
+                                        if relative {
+                                            *path = functions::make_relative(path, &working_dir);
+                                        } else if absolute {
+                                            *path = functions::make_absolute(path, &working_dir);
+                                        }
+                                    };
+                                    for field in schema::Field::PATHS {
+                                        rewrite(form.value_mut(field));
+                                    }
+                                    // The -u file is an option value rather than a path field
+                                    rewrite(&mut form.eval_file);
+                                });
+                                ui.end_row();
+
+                                // Input and output files, enabled for the tasks that use them
+                                for field in [schema::Field::Config, schema::Field::Calibration, schema::Field::Standards, schema::Field::Spectrum, schema::Field::Map, schema::Field::SddData, schema::Field::EdrFile] {
+                                    path_row(ui, form, settings, task_spec, field);
+                                    // The energy calibration override goes with the spectrum it applies to
+                                    if field == schema::Field::Spectrum && task_spec.is_some_and(|spec| spec.accepts("-e")) {
+                                        energy_row(ui, &mut form.energy, history);
+                                    }
                                 }
-                            };
-                        });
-                        ui.end_row();
-                        
-                        // Log file
-                        ui.add(egui::Label::new("Log file (appends)"));
-                        ui.horizontal(|ui| {
-                            ui.add(egui::TextEdit::singleline(log_file)
-                                .hint_text("path to log file (optional)")
-                                .background_color(color_vec[6]));
-                            if ui.button("Browse").clicked() {
-                                // Open file dialog to look for relevant config files
-                                let f = functions::open_fd();
-                                if let Some(path) = f {
-                                    *log_file =  path.into_os_string().into_string().unwrap();
+
+                                // Element fit controls
+                                let element_spec = task_spec.and_then(|spec| spec.field(schema::Field::ElementControls));
+                                ui.add(egui::Label::new(schema::Field::ElementControls.label()));
+                                ui.add_enabled(element_spec.is_some(), egui::TextEdit::singleline(&mut form.element_controls)
+                                    .hint_text(field_hint(schema::Field::ElementControls, element_spec))
+                                    .desired_width(340.0));
+                                ui.end_row();
+                            }
+                        );
+                    });
+
+                    // Separator
+                    ui.add_space(5.0);
+                    ui.separator();
+                    ui.add_space(5.0);
+
+                    // Optional arguments section
+                    ui.heading("Optional arguments");
+                    ui.add_space(10.0);
+
+                    ui.with_layout(egui::Layout::left_to_right(egui::Align::TOP), |ui| {
+                        ui.add_space(125.0);
+
+                        egui::Grid::new("optional_arguments")
+                            .spacing([50.0, 15.0])
+                            .num_columns(2)
+                            .show(ui, |ui| {
+                                // Plot and log files
+                                for field in [schema::Field::Plot, schema::Field::Log] {
+                                    path_row(ui, form, settings, task_spec, field);
                                 }
-                            };
-                        });
-                        ui.end_row();
 
-                        // Extra CLI arguments
-                        ui.add(egui::Label::new("CLI arguments"));
-                        ui.add(egui::TextEdit::singleline(cli_args).hint_text("additional CLI arguments").desired_width(340.0));
-                        ui.end_row();
+                                // Extra outputs of Calibrate and Evaluate
+                                let eval_file = task_spec.is_some_and(|spec| spec.accepts("-u"));
+                                let valid = eval_file && !form.eval_file.is_empty() && !form.eval_file.contains(',') && schema::output_dir_writable(&form.eval_file, &form.working_dir);
+                                let background = if valid {
+                                    Color32::from_rgb(22, 44, 30)
+                                } else {
+                                    eframe::egui::Style::default().visuals.extreme_bg_color
+                                };
+                                // Evaluate only writes its plot for a single standard
+                                let (label, hint) = match form.task {
+                                    Tasks::Evaluate => ("Evaluation plot file (-u)", "path to plot file, for the standard picked with -s"),
+                                    _ => ("Evaluation file (-u)", "path to evaluation file (optional)")
+                                };
+                                ui.add(egui::Label::new(label));
+                                ui.horizontal(|ui| {
+                                    ui.add_enabled(eval_file, egui::TextEdit::singleline(&mut form.eval_file).hint_text(hint).background_color(background));
+                                    if ui.add_enabled(eval_file, egui::Button::new("Browse")).clicked() {
+                                        const EVAL_FILTERS: &[functions::FileFilter] = &[functions::FileFilter { name: "CSV", extensions: &["csv"] }];
+                                        if let Some(path) = browse(settings, "evaluation file", EVAL_FILTERS, &form.working_dir, Some(&file_name(&form.eval_file))) {
+                                            form.eval_file = path.display().to_string();
+                                        }
+                                    }
+                                });
+                                ui.end_row();
+
+                                let min_weight = task_spec.is_some_and(|spec| spec.accepts("-w"));
+                                let weight = form.min_weight.trim();
+                                let background = if weight.is_empty() || weight.parse::<f32>().is_ok_and(f32::is_finite) {
+                                    eframe::egui::Style::default().visuals.extreme_bg_color
+                                } else {
+                                    Color32::from_rgb(70, 20, 20)
+                                };
+                                ui.add(egui::Label::new("Minimum weight (-w)"));
+                                ui.add_enabled(min_weight, egui::TextEdit::singleline(&mut form.min_weight)
+                                    .hint_text("0.15")
+                                    .desired_width(70.0)
+                                    .background_color(background))
+                                    .on_hover_text("Elements whose weight in the standards file is this or less are left out of the evaluation");
+                                ui.end_row();
+
+                                // Extra CLI arguments
+                                // Split like a shell command line; unbalanced quotes block the run
+                                let split = functions::split_args(&form.cli_args);
+                                let background = if split.is_ok() {
+                                    eframe::egui::Style::default().visuals.extreme_bg_color
+                                } else {
+                                    Color32::from_rgb(70, 20, 20)
+                                };
+                                ui.add(egui::Label::new("CLI arguments"));
+                                let response = ui.add(egui::TextEdit::singleline(&mut form.cli_args)
+                                    .hint_text("additional CLI arguments")
+                                    .desired_width(340.0)
+                                    .background_color(background));
+                                if let Err(e) = split {
+                                    response.on_hover_text(e);
+                                }
+                                ui.end_row();
+                            }
+                        );
+                    });
+
+                    // Options section, for tasks that take any
+                    if task_spec.is_some_and(|spec| !spec.options.is_empty()) {
+                        ui.add_space(5.0);
+                        ui.separator();
+                        ui.add_space(5.0);
+                        ui.heading("Options");
+                        ui.add_space(10.0);
+
+                        if task_spec.is_some_and(|spec| spec.accepts(background::LOW.option)) {
+                            egui::CollapsingHeader::new("Background (-b, -bh, -bx)").show(ui, |ui| {
+                                for family in background::FAMILIES {
+                                    param_list_editor(ui, family, form.background.values_mut(family));
+                                }
+                            });
+                        }
+                        let detector_select = task_spec.is_some_and(|spec| spec.accepts("-d"));
+                        let shelf = task_spec.is_some_and(|spec| spec.accepts(detector::SHELF.option));
+                        if detector_select || shelf {
+                            egui::CollapsingHeader::new("Detector (-d, -T)").show(ui, |ui| {
+                                if detector_select {
+                                    ui.horizontal(|ui| {
+                                        for choice in [detector::Detector::Combined, detector::Detector::A, detector::Detector::B, detector::Detector::Separately] {
+                                            ui.radio_value(&mut form.detector, choice, choice.label());
+                                        }
+                                    });
+                                    if form.detector.split().is_some() {
+                                        ui.weak("Runs once per detector through the queue, adding _A or _B to the output file names");
+                                    }
+                                }
+                                if shelf {
+                                    param_list_editor(ui, &detector::SHELF, &mut form.shelf);
+                                }
+                            });
+                        }
+                        if let Some(spec) = task_spec.filter(|spec| spec.accepts("-q")) {
+                            egui::CollapsingHeader::new("Map columns (-q)").show(ui, |ui| {
+                                let default = map_outputs::default_letters(spec.sub_command);
+                                map_outputs_editor(ui, &mut form.map_outputs, default, &mut settings.map_presets, preset_name);
+                            });
+                        }
+                        if task_spec.is_some_and(|spec| spec.accepts("-s")) {
+                            egui::CollapsingHeader::new("Standard (-s)").show(ui, |ui| {
+                                // Evaluate goes through every standard unless one is picked; the others use the first
+                                let unselected = if form.task == Tasks::Evaluate { "All standards" } else { "First standard" };
+                                standard_picker(ui, &mut form.standard, unselected, &form.standards_file, standards_list.as_ref());
+                            });
+                        }
+                        if let Some(spec) = task_spec.filter(|spec| fit_options::OPTIONS.iter().any(|o| spec.accepts(o))) {
+                            egui::CollapsingHeader::new("Fit options (-f, -g, -v, -c, -n, -Fe)").show(ui, |ui| {
+                                fit_options_editor(ui, &mut form.fit, |option| spec.accepts(option));
+                            });
+                        }
+                        for error in option_errors.iter() {
+                            ui.colored_label(Color32::LIGHT_RED, error);
+                        }
                     }
-                );
+                });
             });
 
             // Separator
@@ -323,10 +983,52 @@ impl eframe::App for PiquantApp {
             ui.separator();
             ui.add_space(5.0);
 
+            // Command preview, one line for each run the form stands for
+            let program = program_name(piquant_exe);
+            let runs: Vec<(schema::Form, Vec<String>)> = match task_spec {
+                Some(_) => schema::runs(form).into_iter().map(|run| {
+                    let run_args = schema::args(&run);
+                    (run, run_args)
+                }).collect(),
+                None => Vec::new()
+            };
+            let shell = script::Shell::native();
+            let preview = runs.iter().map(|(_, run_args)| script::command_line(&program, run_args, shell)).collect::<Vec<_>>().join("\n");
+            ui.horizontal(|ui| {
+                ui.label("Command");
+                if ui.add_enabled(!runs.is_empty(), egui::Button::new("Copy")).clicked() {
+                    ctx.copy_text(preview.clone());
+                }
+                if ui.add_enabled(!runs.is_empty(), egui::Button::new("Export as script")).clicked() {
+                    let script_runs: Vec<(&str, &[String])> = runs.iter().map(|(run, run_args)| (run.working_dir.as_str(), run_args.as_slice())).collect();
+                    if let Err(e) = export_script(settings, &program, &script_runs) {
+                        error_banners.push(e);
+                    }
+                }
+            });
+            ui.add(egui::TextEdit::multiline(&mut preview.as_str())
+                .hint_text("pick a task to see the command")
+                .font(egui::TextStyle::Monospace)
+                .desired_width(f32::INFINITY)
+                .desired_rows(runs.len().max(1)));
+
             // Results multi-line textbox section
+            let mut layouter = |ui: &egui::Ui, text: &str, wrap_width: f32| {
+                match output_galley {
+                    Some((length, width, galley)) if *length == text.len() && *width == wrap_width => galley.clone(),
+                    _ => {
+                        let mut layout_job = output_layout_job(text, ui.style());
+                        layout_job.wrap.max_width = wrap_width;
+                        let galley = ui.fonts(|f| f.layout_job(layout_job));
+                        *output_galley = Some((text.len(), wrap_width, galley.clone()));
+                        galley
+                    }
+                }
+            };
             egui::ScrollArea::vertical()
                 .auto_shrink(true)
                 .max_height(130.0)
+                .stick_to_bottom(true)
                 .show(ui, |ui| {
                     ui.with_layout(
                         egui::Layout::top_down(egui::Align::LEFT).with_cross_justify(true),
@@ -335,57 +1037,417 @@ impl eframe::App for PiquantApp {
                                 egui::TextEdit::multiline(output_text)
                                     .hint_text("output").desired_width(f32::INFINITY)
                                     .desired_rows(8)
+                                    .layouter(&mut layouter)
                             );
                         },
                     );
                 });
 
-            // "Execute" button
+            // Job status
+            ui.horizontal(|ui| {
+                match job_state {
+                    runner::JobState::Idle => {ui.label("Ready");},
+                    runner::JobState::Running => {
+                        ui.spinner();
+                        if let Some(running) = job {
+                            ui.label(format!("Running PIQUANT... {}", runner::format_elapsed(running.elapsed())));
+                        }
+                        if let Some(progress) = progress {
+                            let eta = match progress.eta() {
+                                Some(eta) => format!(", ETA {}", runner::format_elapsed(eta)),
+                                None => String::new()
+                            };
+                            ui.add(egui::ProgressBar::new(progress.fraction())
+                                .desired_width(300.0)
+                                .text(format!("{} / {} spectra{}", progress.done, progress.total, eta)));
+                        }
+                    },
+                    runner::JobState::Finished => {ui.label(format!("Finished (exit code {})", exit_code.unwrap_or(0)));},
+                    runner::JobState::Failed => {
+                        match exit_code {
+                            Some(code) => ui.colored_label(Color32::LIGHT_RED, format!("Failed (exit code {})", code)),
+                            None => ui.colored_label(Color32::LIGHT_RED, "Failed")
+                        };
+                    },
+                    runner::JobState::Cancelled => {ui.colored_label(Color32::YELLOW, "Cancelled");}
+                }
+                ui.with_layout(egui::Layout::right_to_left(egui::Align::Center), |ui| {
+                    ui.weak(format!("PIQUANT {}", version_text));
+                });
+            });
+            for warning in warnings.iter() {
+                ui.colored_label(Color32::YELLOW, format!("\u{26A0} {}", warning));
+            }
+
+            // "Execute" button, replaced by "Cancel" while PIQUANT is running
             ui.vertical_centered(|ui| {
-                if ui.add_enabled(enable_vec[7], egui::Button::new("Execute").min_size(egui::Vec2::new(775.0, 20.0))).clicked() {
-                    // Get CWD of Rust piquant executable, assuming the path is accessible.
-                    let cwe = env::current_exe().unwrap();
-                    let cwd = cwe.parent().unwrap();
-
-                    // Relative to the executable path, the PIQUANT CLI exe should be (unless otherwise specified) in ../../lib/cli/bin/
-                    let piquant_exe_dir = format!("{}/../../lib/cli/bin/", cwd.to_str().unwrap());
-                    let piquant_exe;
-
-                    // Clear output text
-                    output_text.clear();
-
-                    // Send arguments to PIQUANT and execute based on platform.
-                    let output = if cfg!(target_os = "windows") {
-                        piquant_exe = "PIQUANT.exe";
-                        Command::new([piquant_exe_dir.as_str(), piquant_exe].concat())
-                            .args(args.clone())
-                            .output()
-                            .expect("failed to execute process")
-                    } else if cfg!(target_os = "macos") {
-                        piquant_exe = "PIQUANT";
-                        Command::new([piquant_exe_dir.as_str(), piquant_exe].concat())
-                            .args(args.clone())
-                            .output()
-                            .expect("failed to execute process")
-                    } else {
-                        piquant_exe = "PIQUANT";
-                        // (Likely) on *nix
-                        Command::new([piquant_exe_dir.as_str(), piquant_exe].concat())
-                            .args(args.clone())
-                            .output()
-                            .expect("failed to execute process")
-                    };
-                    
-                    // Write to output. 
-                    // output_text.push_str(format!("Executable path: {}\n", [piquant_exe_dir.as_str(), piquant_exe].concat()).as_str());
-                    output_text.push_str(format!("Arguments: {:?}\n", args).as_str());
-                    // output_text.push_str("--------------------------- Starting execution -------------------------------\n");
-                    let stdout =  String::from_utf8(output.stdout).unwrap();
-                    // output_text();
-                    output_text.push_str(&stdout);
-                };
+                if running {
+                    if ui.add(egui::Button::new("Cancel").min_size(egui::Vec2::new(775.0, 20.0))).clicked() {
+                        if let Some(active) = job {
+                            active.cancel();
+                        }
+                    }
+                } else {
+                    ui.horizontal(|ui| {
+                        if ui.add_enabled(ready, egui::Button::new("Execute").min_size(egui::Vec2::new(655.0, 20.0))).clicked() {
+                            execute = true;
+                        };
+                        if ui.add_enabled(ready, egui::Button::new("Add to queue").min_size(egui::Vec2::new(110.0, 20.0))).clicked() {
+                            add_to_queue = true;
+                        };
+                    });
+                }
             });
 
         });
+
+        if let Some(form) = restore {
+            self.form = form;
+        }
+
+        // Ask before replacing results from earlier runs
+        let requested = if execute {
+            Some(PendingRun::Execute)
+        } else if add_to_queue {
+            Some(PendingRun::Queue)
+        } else if let Some(id) = retry {
+            Some(PendingRun::Retry(id))
+        } else {
+            rerun.map(|(args, form)| PendingRun::Rerun(args, Box::new(form)))
+        };
+        if let Some(run) = requested {
+            let existing = match &run {
+                PendingRun::Rerun(_, form) => schema::existing_outputs(form),
+                PendingRun::Retry(id) => self.queue.jobs.iter()
+                    .find(|j| j.id == *id)
+                    .map(|j| schema::existing_outputs(&j.form))
+                    .unwrap_or_default(),
+                _ => schema::existing_outputs(&self.form)
+            };
+            if existing.is_empty() {
+                self.carry_out(run);
+            } else {
+                self.overwrite = Some((run, existing));
+            }
+        }
+        if let Some(run) = confirmed {
+            self.carry_out(run);
+        }
     }
-}
\ No newline at end of file
+}
+
+// A run asked for from the form, the history or the queue
+enum PendingRun {
+    Execute,
+    Queue,
+    Rerun(Vec<String>, Box<schema::Form>),
+    // Put a queued job back in line, by its ID since the queue may change while we ask
+    Retry(u64)
+}
+
+// Buttons on each row of the queue window
+enum QueueAction {
+    MoveUp,
+    MoveDown,
+    Retry,
+    Remove
+}
+
+/// The PIQUANT executable to show in commands and scripts; its plain name if it hasn't been found
+fn program_name(piquant_exe: &Option<(PathBuf, runner::PathSource)>) -> String {
+    match piquant_exe {
+        Some((path, _)) => path.display().to_string(),
+        None => runner::piquant_exe_name().to_string()
+    }
+}
+
+/// Ask where to save a script repeating the given runs, and write it
+fn export_script(settings: &mut settings::Settings, program: &str, runs: &[(&str, &[String])]) -> Result<(), String> {
+    let shell = script::Shell::native();
+    let Some(path) = browse(settings, "script", shell.filters(), "", Some(shell.file_name())) else {
+        return Ok(());
+    };
+    script::write(&path, &script::script(program, runs, shell))
+        .map_err(|e| format!("Could not write the script to {}: {}", path.display(), e))
+}
+
+/// Browse for a file of the given kind, starting where one was last picked, or else in the working directory
+fn browse(settings: &mut settings::Settings, key: &str, filters: &[functions::FileFilter], working_dir: &str, save_as: Option<&str>) -> Option<PathBuf> {
+    let dir = settings.last_dir(key).or_else(|| Some(PathBuf::from(working_dir)).filter(|d| !d.as_os_str().is_empty()));
+    let picked = match save_as {
+        Some(file_name) => functions::save_fd(file_name, filters, dir.as_deref()),
+        None => functions::open_fd(filters, dir.as_deref())
+    }?;
+    settings.remember_dir(key, &picked);
+    Some(picked)
+}
+
+/// A path field with its label and Browse button, enabled and coloured according to how the task uses it
+fn path_row(ui: &mut egui::Ui, form: &mut schema::Form, settings: &mut settings::Settings, task_spec: Option<&schema::TaskSpec>, field: schema::Field) {
+    let field_spec = task_spec.and_then(|spec| spec.field(field));
+    let enabled = field_spec.is_some();
+    let valid = field_spec.is_some_and(|f| !form.value(field).is_empty() && schema::check_field(f, form.value(field), &form.working_dir));
+    let background = if valid {
+        Color32::from_rgb(22, 44, 30)
+    } else {
+        eframe::egui::Style::default().visuals.extreme_bg_color
+    };
+
+    ui.add(egui::Label::new(field.label()));
+    ui.horizontal(|ui| {
+        ui.add_enabled(enabled, egui::TextEdit::singleline(form.value_mut(field))
+            .hint_text(field_hint(field, field_spec))
+            .background_color(background));
+        if ui.add_enabled(enabled, egui::Button::new("Browse")).clicked() {
+            // Files PIQUANT writes are chosen with a save dialog, so they needn't exist yet. A log is added
+            // to rather than replaced, so it's picked like an input to avoid the dialog's replace warning
+            let save_as = match field_spec.map(|f| f.direction) {
+                Some(schema::Direction::Output) => Some(file_name(form.value(field))),
+                _ => None
+            };
+            let f = browse(settings, field.name(), field.filters(), &form.working_dir, save_as.as_deref());
+            if let Some(path) = f {
+                *form.value_mut(field) = path.display().to_string();
+            }
+        };
+    });
+    ui.end_row();
+}
+
+/// Toggle and values for the -e energy calibration override, which can be copied from an Energy Calibration run
+fn energy_row(ui: &mut egui::Ui, energy: &mut energy_calibration::EnergyCalibration, history: &history::History) {
+    let invalid = Color32::from_rgb(70, 20, 20);
+    let errors = energy.errors();
+    ui.checkbox(&mut energy.enabled, "Energy calibration (-e)");
+    ui.horizontal(|ui| {
+        let start_background = if errors.iter().any(|e| e.contains("eV start")) { invalid } else { eframe::egui::Style::default().visuals.extreme_bg_color };
+        let per_channel_background = if errors.iter().any(|e| e.contains("eV/ch")) { invalid } else { eframe::egui::Style::default().visuals.extreme_bg_color };
+        ui.add_enabled(energy.enabled, egui::TextEdit::singleline(&mut energy.start).hint_text("eV start").desired_width(80.0).background_color(start_background));
+        ui.add_enabled(energy.enabled, egui::TextEdit::singleline(&mut energy.per_channel).hint_text("eV/ch").desired_width(80.0).background_color(per_channel_background));
+
+        // The most recent Energy Calibration run that found a calibration
+        let last = history.entries.iter().rev()
+            .filter(|entry| entry.form.task == Tasks::EnergyCalibration)
+            .find_map(|entry| energy_calibration::from_output(&entry.output).map(|found| (entry, found)));
+        let button = ui.add_enabled(last.is_some(), egui::Button::new("From last Energy Calibration"))
+            .on_disabled_hover_text("No Energy Calibration run in the history has found a calibration");
+        if let Some((entry, (start, per_channel))) = last {
+            if button.on_hover_text(format!("eV start {}, eV/ch {}, from {}", start, per_channel, entry.form.spectrum_file)).clicked() {
+                energy.start = start;
+                energy.per_channel = per_channel;
+                energy.enabled = true;
+            }
+        }
+    });
+    ui.end_row();
+}
+
+/// Toggle and parameter inputs for an option taking a list of numbers, with the option string they produce
+fn param_list_editor(ui: &mut egui::Ui, family: &background::Family, values: &mut background::ParamValues) {
+    values.values.resize(family.params.len(), String::new());
+
+    ui.checkbox(&mut values.enabled, format!("{} ({})", family.title, family.option));
+    if !values.enabled {
+        return;
+    }
+    let last = values.values.iter().rposition(|v| !v.trim().is_empty());
+    ui.indent(family.option, |ui| {
+        ui.weak(family.description);
+        for (index, (param, value)) in family.params.iter().zip(values.values.iter_mut()).enumerate() {
+            let background = if background::check_value(param, value).is_ok() {
+                eframe::egui::Style::default().visuals.extreme_bg_color
+            } else {
+                Color32::from_rgb(70, 20, 20)
+            };
+            // Blank entries show PIQUANT's default, or what is sent in their place before a later value
+            let hint = if index < family.defaults.len() || last.is_some_and(|last| last > index) {
+                background::blank_value(family, index)
+            } else {
+                String::new()
+            };
+            ui.horizontal(|ui| {
+                ui.add_sized([110.0, 18.0], egui::Label::new(param.label));
+                ui.add(egui::TextEdit::singleline(value)
+                    .hint_text(hint)
+                    .desired_width(70.0)
+                    .background_color(background));
+                ui.add(egui::Label::new(egui::RichText::new(param.meaning).weak().small()).wrap());
+            });
+        }
+        match background::option_string(family, values) {
+            Some(option) => {ui.monospace(option);},
+            None => {ui.weak("No values entered; PIQUANT's defaults are used");}
+        }
+    });
+}
+
+/// Checkboxes for every map column, with presets and the -q option they produce
+fn map_outputs_editor(ui: &mut egui::Ui, outputs: &mut map_outputs::MapOutputs, default: &str, presets: &mut Vec<map_outputs::Preset>, preset_name: &mut String) {
+    ui.checkbox(&mut outputs.enabled, "Choose the columns written to the map file");
+    if !outputs.enabled {
+        ui.weak(format!("PIQUANT writes its default columns for this task (-q,{})", default));
+        return;
+    }
+
+    // Presets: built-in ones first, then those saved by the user
+    ui.horizontal(|ui| {
+        egui::ComboBox::from_id_salt("map_presets")
+            .selected_text("Load preset")
+            .show_ui(ui, |ui| {
+                for (name, letters) in map_outputs::BUILT_IN_PRESETS {
+                    if ui.selectable_label(outputs.letters == letters, name).clicked() {
+                        outputs.letters = letters.to_string();
+                    }
+                }
+                for preset in presets.iter() {
+                    if ui.selectable_label(outputs.letters == preset.letters, preset.name.as_str()).clicked() {
+                        outputs.letters = preset.letters.clone();
+                        *preset_name = preset.name.clone();
+                    }
+                }
+            });
+        ui.add(egui::TextEdit::singleline(preset_name).hint_text("preset name").desired_width(140.0));
+        let can_save = !preset_name.trim().is_empty() && !outputs.letters.is_empty();
+        if ui.add_enabled(can_save, egui::Button::new("Save preset")).clicked() {
+            let name = preset_name.trim().to_string();
+            match presets.iter_mut().find(|p| p.name == name) {
+                Some(preset) => preset.letters = outputs.letters.clone(),
+                None => presets.push(map_outputs::Preset { name, letters: outputs.letters.clone() })
+            }
+        }
+        let saved = presets.iter().any(|p| p.name == preset_name.trim());
+        if ui.add_enabled(saved, egui::Button::new("Delete preset")).clicked() {
+            presets.retain(|p| p.name != preset_name.trim());
+        }
+    });
+
+    // Columns are written in the order they are ticked
+    for group in map_outputs::GROUPS.iter() {
+        ui.add_space(5.0);
+        ui.strong(group.title);
+        egui::Grid::new(group.title).num_columns(3).spacing([15.0, 4.0]).show(ui, |ui| {
+            for column in group.columns {
+                let mut selected = outputs.contains(column.letter);
+                if ui.checkbox(&mut selected, column.letter.to_string()).changed() {
+                    outputs.set(column.letter, selected);
+                }
+                ui.monospace(column.header);
+                ui.weak(column.description);
+                ui.end_row();
+            }
+        });
+    }
+    ui.add_space(5.0);
+    match outputs.arg() {
+        Some(option) => {ui.monospace(option);},
+        None => {ui.weak("No columns selected");}
+    }
+}
+
+/// Drop-down of the standards in the standards input file, setting -s
+fn standard_picker(ui: &mut egui::Ui, selected: &mut String, unselected: &str, standards_file: &str, list: Option<&standards::StandardsList>) {
+    if standards_file.is_empty() {
+        ui.weak("Choose a standards input file to pick a standard from it");
+        return;
+    }
+    let standards = match list.map(|l| &l.standards) {
+        Some(Ok(standards)) => standards.as_slice(),
+        Some(Err(e)) => {
+            ui.colored_label(Color32::LIGHT_RED, format!("Could not read the standards: {}", e));
+            return;
+        }
+        None => &[]
+    };
+    let current = standards::find(selected, standards);
+    let text = match current {
+        _ if selected.is_empty() => unselected.to_string(),
+        Some(standard) => standard.label(),
+        None => format!("{} (not in the standards file)", selected)
+    };
+    egui::ComboBox::from_id_salt("standard")
+        .selected_text(text)
+        .width(340.0)
+        .show_ui(ui, |ui| {
+            if ui.selectable_label(selected.is_empty(), unselected).clicked() {
+                selected.clear();
+            }
+            for standard in standards {
+                let chosen = current.is_some_and(|c| c.index == standard.index);
+                if ui.selectable_label(chosen, standard.label()).clicked() {
+                    *selected = standards::selection(standard, standards);
+                }
+            }
+        });
+    if !selected.is_empty() {
+        ui.monospace(format!("-s,{}", selected));
+    }
+}
+
+/// Switches and typed values for the fit options a task accepts
+fn fit_options_editor(ui: &mut egui::Ui, fit: &mut fit_options::FitOptions, accepts: impl Fn(&str) -> bool) {
+    if accepts("-f") {
+        ui.checkbox(&mut fit.freeze_energy, "Freeze the energy calibration during fits (-f)");
+    }
+    if accepts("-g") {
+        ui.checkbox(&mut fit.freeze_width, "Freeze the detector resolution during fits (-g)");
+    }
+    if accepts("-v") {
+        ui.checkbox(&mut fit.convolve_compton, "Convolve Compton scatter with the detector resolution (-v)");
+    }
+    if accepts("-c") {
+        ui.checkbox(&mut fit.carbonates, "Carbonates instead of oxides (-c)");
+        if fit.carbonates {
+            ui.indent("carbonates", |ui| {
+                ui.horizontal(|ui| {
+                    ui.add_sized([110.0, 18.0], egui::Label::new("Elements"));
+                    ui.add(egui::TextEdit::singleline(&mut fit.carbonate_elements).hint_text("all that can be").desired_width(140.0));
+                });
+                ui.weak(format!("Only {} form carbonates. Listed elements are put after \"CO3\" in the element fit controls.", fit_options::CARBONATE_ELEMENTS.join(", ")));
+            });
+        }
+    }
+    if accepts("-n") {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut fit.normalize, "Normalize the element sum to (%) (-n)");
+            ui.add_enabled(fit.normalize, egui::TextEdit::singleline(&mut fit.normalization).hint_text("100").desired_width(70.0));
+        });
+    }
+    if accepts("-Fe") {
+        ui.horizontal(|ui| {
+            ui.checkbox(&mut fit.iron_ratio_enabled, "Oxygen atoms per Fe atom (-Fe)");
+            ui.add_enabled(fit.iron_ratio_enabled, egui::TextEdit::singleline(&mut fit.iron_ratio).hint_text("1.5 for Fe2O3").desired_width(100.0));
+        });
+    }
+}
+
+/// The file name part of a path typed into the form, offered again by save dialogs
+fn file_name(path: &str) -> String {
+    std::path::Path::new(path).file_name().map(|n| n.to_string_lossy().to_string()).unwrap_or_default()
+}
+
+/// Hint text for an empty field, noting when the selected task can do without it
+fn field_hint(field: schema::Field, field_spec: Option<&schema::FieldSpec>) -> String {
+    let hint = match field {
+        schema::Field::ElementControls => String::from("FE_[KLMN] [IFX]"),
+        _ => format!("path to {}", field.name())
+    };
+    match field_spec.map(|f| f.need) {
+        Some(schema::Need::Required) | None => hint,
+        Some(_) => format!("{} (optional)", hint)
+    }
+}
+
+/// Colour stderr lines in the output pane so they stand out from stdout
+fn output_layout_job(text: &str, style: &egui::Style) -> egui::text::LayoutJob {
+    let mut layout_job = egui::text::LayoutJob::default();
+    let font_id = egui::TextStyle::Monospace.resolve(style);
+    for line in text.split_inclusive('\n') {
+        let color = if line.starts_with(STDERR_MARKER) {
+            Color32::LIGHT_RED
+        } else {
+            style.visuals.text_color()
+        };
+        layout_job.append(line, 0.0, egui::TextFormat::simple(font_id.clone(), color));
+    }
+    layout_job
+}
@@ -0,0 +1,204 @@
+// Description: record of past PIQUANT runs, kept between sessions.
+
+use serde::{Deserialize, Serialize};
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use crate::runner;
+use crate::schema;
+
+// Key the history is stored under in eframe's storage
+const HISTORY_KEY: &str = "piquant_history";
+
+// Oldest runs are dropped beyond this many
+const MAX_ENTRIES: usize = 100;
+
+// Only the end of each run's output is kept, as the whole history is saved with every autosave;
+// the last lines hold the results and any error, and the full output can be sent to a log file
+const MAX_OUTPUT_BYTES: usize = 16 * 1024;
+
+/// A single PIQUANT run
+#[derive(Debug, Serialize, Deserialize)]
+pub struct HistoryEntry {
+    pub form: schema::Form,
+    pub args: Vec<String>,
+    // Seconds since the Unix epoch
+    pub started: u64,
+    pub ended: Option<u64>,
+    pub state: runner::JobState,
+    pub exit_code: Option<i32>,
+    pub output: String
+}
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct History {
+    // Oldest first
+    pub entries: Vec<HistoryEntry>
+}
+
+impl History {
+    /// Whether starting another run drops the oldest, moving every other entry down one index
+    pub fn is_full(&self) -> bool {
+        self.entries.len() >= MAX_ENTRIES
+    }
+
+    /// Record a run that is starting, returning its index
+    pub fn start(&mut self, form: schema::Form, args: Vec<String>) -> usize {
+        if self.is_full() {
+            self.entries.remove(0);
+        }
+        self.entries.push(HistoryEntry {
+            form,
+            args,
+            started: now(),
+            ended: None,
+            state: runner::JobState::Running,
+            exit_code: None,
+            output: String::new()
+        });
+        self.entries.len() - 1
+    }
+
+    /// Record how a run ended and what it printed
+    pub fn finish(&mut self, index: usize, state: runner::JobState, exit_code: Option<i32>, output: &str) {
+        if let Some(entry) = self.entries.get_mut(index) {
+            entry.ended = Some(now());
+            entry.state = state;
+            entry.exit_code = exit_code;
+            entry.output = tail(output, MAX_OUTPUT_BYTES).to_string();
+        }
+    }
+}
+
+/// Load the history saved by a previous session
+pub fn load(storage: Option<&dyn eframe::Storage>) -> History {
+    let mut history: History = storage
+        .and_then(|s| eframe::get_value(s, HISTORY_KEY))
+        .unwrap_or_default();
+    // A run still marked as running was interrupted when the app closed
+    for entry in history.entries.iter_mut().filter(|e| e.state == runner::JobState::Running) {
+        entry.state = runner::JobState::Cancelled;
+    }
+    history
+}
+
+/// Save the history for the next session
+pub fn save(storage: &mut dyn eframe::Storage, history: &History) {
+    eframe::set_value(storage, HISTORY_KEY, history);
+}
+
+/// Current time in seconds since the Unix epoch
+fn now() -> u64 {
+    SystemTime::now().duration_since(UNIX_EPOCH).map(|d| d.as_secs()).unwrap_or(0)
+}
+
+/// Last max_bytes of a string, cut at a character boundary
+fn tail(text: &str, max_bytes: usize) -> &str {
+    if text.len() <= max_bytes {
+        return text;
+    }
+    let mut start = text.len() - max_bytes;
+    while !text.is_char_boundary(start) {
+        start += 1;
+    }
+    &text[start..]
+}
+
+/// Format seconds since the Unix epoch as "YYYY-MM-DD HH:MM:SS UTC"
+pub fn format_time(secs: u64) -> String {
+    let days = (secs / 86400) as i64;
+    let rem = secs % 86400;
+
+    // Convert days since 1970-01-01 to a civil date (Howard Hinnant's days_from_civil, inverted)
+    let z = days + 719468;
+    let era = z.div_euclid(146097);
+    let doe = z.rem_euclid(146097);
+    let yoe = (doe - doe / 1460 + doe / 36524 - doe / 146096) / 365;
+    let doy = doe - (365 * yoe + yoe / 4 - yoe / 100);
+    let mp = (5 * doy + 2) / 153;
+    let day = doy - (153 * mp + 2) / 5 + 1;
+    let month = if mp < 10 { mp + 3 } else { mp - 9 };
+    let year = yoe + era * 400 + if month <= 2 { 1 } else { 0 };
+
+    format!("{:04}-{:02}-{:02} {:02}:{:02}:{:02} UTC", year, month, day, rem / 3600, (rem % 3600) / 60, rem % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    #[derive(Default)]
+    struct MemoryStorage(HashMap<String, String>);
+
+    impl eframe::Storage for MemoryStorage {
+        fn get_string(&self, key: &str) -> Option<String> {
+            self.0.get(key).cloned()
+        }
+
+        fn set_string(&mut self, key: &str, value: String) {
+            self.0.insert(key.to_string(), value);
+        }
+
+        fn flush(&mut self) {}
+    }
+
+    #[test]
+    fn oldest_entries_are_dropped() {
+        let mut history = History::default();
+        for run in 0..MAX_ENTRIES {
+            assert!(!history.is_full());
+            assert_eq!(history.start(schema::Form::default(), vec![run.to_string()]), run);
+        }
+        assert!(history.is_full());
+        assert_eq!(history.start(schema::Form::default(), vec![String::from("last")]), MAX_ENTRIES - 1);
+        assert_eq!(history.entries.len(), MAX_ENTRIES);
+        assert_eq!(history.entries[0].args, ["1"]);
+        assert_eq!(history.entries[MAX_ENTRIES - 1].args, ["last"]);
+    }
+
+    #[test]
+    fn output_tail() {
+        assert_eq!(tail("short", 10), "short");
+        // Cutting at 3 bytes from the end would split the first é
+        assert_eq!(tail("aéé", 3), "é");
+        assert_eq!(tail("abcdef", 2), "ef");
+    }
+
+    #[test]
+    fn long_output_is_cut() {
+        let mut history = History::default();
+        let index = history.start(schema::Form::default(), vec![String::from("map")]);
+        let output = format!("{}Energy calibration (-e,1.5,7.8)\n", "spectrum read\n".repeat(10_000));
+        history.finish(index, runner::JobState::Finished, Some(0), &output);
+        let kept = &history.entries[index].output;
+        assert_eq!(kept.len(), MAX_OUTPUT_BYTES);
+        assert!(output.ends_with(kept.as_str()));
+    }
+
+    #[test]
+    fn times() {
+        assert_eq!(format_time(0), "1970-01-01 00:00:00 UTC");
+        assert_eq!(format_time(951_782_400 + 3_723), "2000-02-29 01:02:03 UTC");
+        assert_eq!(format_time(1_735_689_599), "2024-12-31 23:59:59 UTC");
+    }
+
+    #[test]
+    fn saved_and_loaded() {
+        let mut history = History::default();
+        let form = schema::Form { spectrum_file: String::from("bhvo.msa"), ..Default::default() };
+        let finished = history.start(form, vec![String::from("ene")]);
+        history.finish(finished, runner::JobState::Finished, Some(0), "Energy calibration (-e,1.5,7.8)\n");
+        history.start(schema::Form::default(), vec![String::from("map")]);
+
+        let mut storage = MemoryStorage::default();
+        save(&mut storage, &history);
+        let loaded = load(Some(&storage));
+        assert_eq!(loaded.entries.len(), 2);
+        assert_eq!(loaded.entries[0].form.spectrum_file, "bhvo.msa");
+        assert_eq!(loaded.entries[0].state, runner::JobState::Finished);
+        assert_eq!(loaded.entries[0].output, "Energy calibration (-e,1.5,7.8)\n");
+        // The run left running when the app closed comes back cancelled
+        assert_eq!(loaded.entries[1].state, runner::JobState::Cancelled);
+    }
+}
@@ -4,12 +4,16 @@ use clap::{Command, Arg, ArgMatches};
 
 // Get all CLI arguments, if they exist. 
 pub fn get_args() -> ArgMatches {
-    let matches = Command::new(env!("CARGO_PKG_NAME"))
+    Command::new(env!("CARGO_PKG_NAME"))
             .version(env!("CARGO_PKG_VERSION"))
             .author(env!("CARGO_PKG_AUTHORS"))
             .about(env!("CARGO_PKG_DESCRIPTION"))
-            .get_matches();
-    matches
+            .arg(Arg::new("piquant")
+                .long("piquant")
+                .value_name("PATH")
+                .help("Path to the PIQUANT executable (overrides PIQUANT_PATH and saved settings)")
+                .required(false))
+            .get_matches()
 }
 
 // .arg(Arg::new("INPUT") // add below .about
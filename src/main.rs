@@ -2,9 +2,25 @@
 // Author: Parker Lamb
 // Description: Lightweight and speedy image-viewing application. 
 
+mod background;
+mod compat;
+mod detector;
+mod energy_calibration;
+mod fit_options;
 mod functions;
+mod history;
 mod input;
+mod map_outputs;
+mod plot;
 mod gui;
+mod progress;
+mod queue;
+mod runner;
+mod schema;
+mod script;
+mod settings;
+mod standards;
+mod xray_lines;
 
 fn main() -> eframe::Result<()> {
     // Initialize logging system
@@ -12,20 +28,20 @@ fn main() -> eframe::Result<()> {
     
     // 1. Get application CLI arguments
     let arguments = input::get_args();
+    let piquant_arg = arguments.get_one::<String>("piquant").cloned();
 
     // 2. Set up GUI options
-    let app_title = concat!(env!("CARGO_PKG_NAME"), " ", env!("CARGO_PKG_VERSION"));
     let native_options = eframe::NativeOptions {
         viewport: eframe::egui::ViewportBuilder::default()
-            .with_min_inner_size(eframe::egui::vec2(800.0, 660.0))
+            .with_min_inner_size(eframe::egui::vec2(800.0, 720.0))
             .with_resizable(false),
             ..Default::default()
     };
 
     // 3. Start up app
     eframe::run_native(
-        app_title,
+        gui::APP_TITLE,
         native_options,
-        Box::new(move |cc| Ok(Box::new(gui::PiquantApp::new(cc))))
+        Box::new(move |cc| Ok(Box::new(gui::PiquantApp::new(cc, piquant_arg))))
     )
 }
\ No newline at end of file
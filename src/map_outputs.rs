@@ -0,0 +1,158 @@
+// Description: map file column selection for the -q option, with built-in and saved presets.
+// Letters and column headers are taken from quantWriteMap.cpp; the defaults from PIQUANT_CommandLine.cpp.
+
+use serde::{Deserialize, Serialize};
+
+/// A letter accepted by -q and the column(s) it adds to the map file
+pub struct Column {
+    pub letter: char,
+    pub header: &'static str,
+    pub description: &'static str
+}
+
+/// Related columns, shown together in the picker
+pub struct Group {
+    pub title: &'static str,
+    pub columns: &'static [Column]
+}
+
+pub const GROUPS: [Group; 4] = [
+    Group {
+        title: "Per element (one column for each element)",
+        columns: &[
+            Column { letter: 'P', header: "_%", description: "Weight percent of the element or oxide" },
+            Column { letter: 'I', header: "_int", description: "Net peak intensity" },
+            Column { letter: 'E', header: "_err", description: "Total error, including the calibration factor uncertainty" },
+            Column { letter: 'L', header: "_coeff", description: "Fit coefficient" },
+            Column { letter: 'K', header: "_ECF", description: "Element calibration factor used" },
+            Column { letter: 'G', header: "_Given", description: "Given composition, from the standards file" },
+            Column { letter: 'H', header: "_errG", description: "Error relative to the given composition" },
+            Column { letter: 'W', header: "_M", description: "Matrix effect factor from the fundamental parameters calculation" }
+        ]
+    },
+    Group {
+        title: "Per spectrum",
+        columns: &[
+            Column { letter: 'T', header: "total_counts", description: "Total counts in the spectrum" },
+            Column { letter: 'X', header: "chisq", description: "Reduced chi squared of the fit" },
+            Column { letter: 'C', header: "eVstart, eV/ch", description: "Energy calibration" },
+            Column { letter: 'R', header: "res", description: "Detector resolution" },
+            Column { letter: 'N', header: "iter", description: "Number of fit iterations" },
+            Column { letter: 'F', header: "filename", description: "Spectrum file name" },
+            Column { letter: 'S', header: "sum_%", description: "Sum of the element percents, to compare with 100%" },
+            Column { letter: 'Q', header: "seq#", description: "Sequence number from the spectrum file name" },
+            Column { letter: 'V', header: "livetime", description: "Live time" },
+            Column { letter: 'M', header: "realtime", description: "Real time" },
+            Column { letter: '7', header: "region_counts", description: "Counts between 1 and 7.25 keV" },
+            Column { letter: 'U', header: "Title", description: "Spectrum title, or standard name in Calibrate and Evaluate" }
+        ]
+    },
+    Group {
+        title: "Spectrum header and position",
+        columns: &[
+            Column { letter: 'p', header: "PMC", description: "PIXL motor count" },
+            Column { letter: 'x', header: "X", description: "Beam X position" },
+            Column { letter: 'y', header: "Y", description: "Beam Y position" },
+            Column { letter: 'z', header: "Z", description: "Beam Z position" },
+            Column { letter: 'i', header: "I", description: "Image I coordinate" },
+            Column { letter: 'j', header: "J", description: "Image J coordinate" },
+            Column { letter: 's', header: "SCLK", description: "Spacecraft clock" },
+            Column { letter: 'r', header: "RTT", description: "Round trip token" },
+            Column { letter: 'd', header: "DPC", description: "Data product category" },
+            Column { letter: 'n', header: "USN", description: "Unique sequence number" },
+            Column { letter: 'e', header: "Events", description: "Detector events" },
+            Column { letter: 't', header: "Triggers", description: "Detector triggers" },
+            Column { letter: 'o', header: "Overflows", description: "Overflow count" },
+            Column { letter: 'u', header: "Underflows", description: "Underflow count" },
+            Column { letter: 'b', header: "baseline_samples", description: "Baseline samples" },
+            Column { letter: 'a', header: "Resets", description: "Preamplifier resets" },
+            Column { letter: 'l', header: "Fast_livetime", description: "Live time from the digital pulse processor" }
+        ]
+    },
+    Group {
+        title: "Header labels",
+        columns: &[
+            Column { letter: 'Z', header: "26_%", description: "Label the per element columns with atomic numbers instead of symbols; adds no column of its own" }
+        ]
+    }
+];
+
+// Letters that change how other columns are labelled rather than adding one
+const MODIFIERS: &[char] = &['Z'];
+
+/// A named selection of columns
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Preset {
+    pub name: String,
+    pub letters: String
+}
+
+// Presets always offered, ahead of any the user saves
+pub const BUILT_IN_PRESETS: [(&str, &str); 6] = [
+    ("Map default", "pPIETVXCRNFetsr"),
+    ("Calibrate / Evaluate default", "GPEHKLF"),
+    ("Percentages", "FPS"),
+    ("Percentages with errors", "FPES"),
+    ("Fit diagnostics", "FPIELXCRNT"),
+    ("Positions for imaging", "pxyzijPE")
+];
+
+/// Columns chosen for the map file; when disabled PIQUANT picks its own default
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct MapOutputs {
+    pub enabled: bool,
+    // In the order the columns are written
+    pub letters: String
+}
+
+impl MapOutputs {
+    pub fn contains(&self, letter: char) -> bool {
+        self.letters.contains(letter)
+    }
+
+    /// Add a column at the end, or remove it
+    pub fn set(&mut self, letter: char, selected: bool) {
+        if selected && !self.contains(letter) {
+            self.letters.push(letter);
+        } else if !selected {
+            self.letters.retain(|c| c != letter);
+        }
+    }
+
+    /// The -q option, if a selection is made
+    pub fn arg(&self) -> Option<String> {
+        if !self.enabled || self.letters.is_empty() {
+            return None;
+        }
+        Some(format!("-q,{}", self.letters))
+    }
+
+    /// Problems with the selection
+    pub fn errors(&self) -> Vec<String> {
+        if !self.enabled {
+            return Vec::new();
+        }
+        if self.letters.chars().all(|c| MODIFIERS.contains(&c)) {
+            return vec![String::from("-q: select at least one map column")];
+        }
+        self.letters.chars()
+            .filter(|&c| column(c).is_none())
+            .map(|c| format!("-q: '{}' is not a map column PIQUANT knows", c))
+            .collect()
+    }
+}
+
+/// Look up a column by its letter
+pub fn column(letter: char) -> Option<&'static Column> {
+    GROUPS.iter().flat_map(|g| g.columns.iter()).find(|c| c.letter == letter)
+}
+
+/// Columns PIQUANT writes when -q isn't given, for the given sub-command
+pub fn default_letters(sub_command: &str) -> &'static str {
+    match sub_command {
+        "cal" | "eva" => "GPEHKLF",
+        _ => "pPIETVXCRNFetsr"
+    }
+}
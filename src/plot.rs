@@ -0,0 +1,401 @@
+// Description: viewer for the plot CSV files PIQUANT writes (quantWritePlot.cpp), drawn with egui_plot.
+// The file has a title line, a header naming the energy or channel axis and each series, then one row per channel.
+
+use eframe::egui::{self, Color32, Pos2, Stroke};
+use egui_plot::{Legend, Line, Plot, PlotPoint, PlotPoints, PlotTransform, VLine};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::xray_lines;
+
+// Series colours, in the order the columns appear
+const COLORS: [Color32; 10] = [
+    Color32::from_rgb(230, 230, 230),
+    Color32::from_rgb(230, 90, 80),
+    Color32::from_rgb(90, 160, 240),
+    Color32::from_rgb(120, 200, 100),
+    Color32::from_rgb(240, 190, 60),
+    Color32::from_rgb(190, 120, 230),
+    Color32::from_rgb(80, 210, 200),
+    Color32::from_rgb(240, 140, 190),
+    Color32::from_rgb(170, 170, 110),
+    Color32::from_rgb(150, 150, 240)
+];
+
+// Marker colours, in the order elements were picked
+const MARKER_COLORS: [Color32; 6] = [
+    Color32::from_rgb(255, 220, 90),
+    Color32::from_rgb(120, 230, 255),
+    Color32::from_rgb(255, 130, 220),
+    Color32::from_rgb(170, 255, 140),
+    Color32::from_rgb(255, 170, 110),
+    Color32::from_rgb(200, 180, 255)
+];
+
+// Weakest line given a marker, relative to the lines filling the same vacancy
+const MARKER_MIN: f32 = 0.02;
+
+// Closest two marker labels of one element may be, in points
+const LABEL_SPACING: f32 = 30.0;
+
+/// One column of a plot file
+#[derive(Debug, Clone, PartialEq)]
+pub struct Series {
+    pub name: String,
+    pub values: Vec<f64>
+}
+
+/// A parsed plot file
+#[derive(Debug, Clone, PartialEq)]
+pub struct PlotData {
+    pub title: String,
+    // "Energy (keV)", or "Channel" for a spectrum without an energy calibration
+    pub x_label: String,
+    pub x: Vec<f64>,
+    pub series: Vec<Series>
+}
+
+impl PlotData {
+    /// Whether the x axis is energy in keV, rather than channel numbers
+    pub fn is_energy(&self) -> bool {
+        self.x_label.starts_with("Energy")
+    }
+
+    /// Index of the row nearest to an x value
+    pub fn nearest(&self, x: f64) -> Option<usize> {
+        let after = self.x.partition_point(|&v| v < x);
+        match (after.checked_sub(1), self.x.get(after)) {
+            (Some(before), Some(&next)) if x - self.x[before] < next - x => Some(before),
+            (_, Some(_)) => Some(after),
+            (Some(before), None) => Some(before),
+            (None, None) => None
+        }
+    }
+}
+
+/// Read a plot file written by PIQUANT
+pub fn read(path: &Path) -> Result<PlotData, String> {
+    let text = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    parse(&text)
+}
+
+/// Parse the contents of a plot file
+pub fn parse(text: &str) -> Result<PlotData, String> {
+    let mut lines = text.lines().filter(|l| !l.trim().is_empty());
+    let title = lines.next().ok_or("the plot file is empty")?.trim().to_string();
+    let header: Vec<&str> = lines.next().ok_or("the plot file has no column header")?.split(',').map(str::trim).collect();
+    let mut data = PlotData {
+        title,
+        x_label: header[0].to_string(),
+        x: Vec::new(),
+        series: header[1..].iter().map(|name| Series { name: name.to_string(), values: Vec::new() }).collect()
+    };
+    for (number, line) in lines.enumerate() {
+        let values: Vec<f64> = line.split(',')
+            .map(|v| v.trim().parse::<f64>())
+            .collect::<Result<_, _>>()
+            .map_err(|_| format!("row {} holds something that isn't a number", number + 1))?;
+        if values.len() != header.len() {
+            return Err(format!("row {} has {} values for {} columns", number + 1, values.len(), header.len()));
+        }
+        data.x.push(values[0]);
+        for (series, value) in data.series.iter_mut().zip(&values[1..]) {
+            series.values.push(*value);
+        }
+    }
+    if data.x.is_empty() {
+        return Err(String::from("the plot file has no data"));
+    }
+    Ok(data)
+}
+
+/// A plot file opened in the viewer, and how it is being shown
+pub struct PlotViewer {
+    pub path: PathBuf,
+    pub data: Result<PlotData, String>,
+    // y is plotted as log10 of the values; egui_plot has no log axis of its own
+    pub log_scale: bool,
+    // Atomic numbers of the elements whose lines are marked
+    pub elements: Vec<u8>
+}
+
+impl PlotViewer {
+    /// Open a plot file, marking the lines of the elements in an element list
+    pub fn open(path: &Path, element_list: &str) -> PlotViewer {
+        PlotViewer {
+            path: path.to_path_buf(),
+            data: read(path),
+            log_scale: false,
+            elements: xray_lines::elements_in(element_list)
+        }
+    }
+
+    /// Read the file again, keeping the view and marked elements
+    pub fn reload(&mut self) {
+        self.data = read(&self.path);
+    }
+}
+
+/// Runs of points to draw as lines. On a log scale, values of 0 or less break the line; nan and inf always do
+pub fn segments(x: &[f64], values: &[f64], log_scale: bool) -> Vec<Vec<[f64; 2]>> {
+    let mut segments = vec![Vec::new()];
+    for (&x, &value) in x.iter().zip(values) {
+        let y = match log_scale {
+            _ if !value.is_finite() => None,
+            true if value > 0.0 => Some(value.log10()),
+            true => None,
+            false => Some(value)
+        };
+        match y {
+            Some(y) => segments.last_mut().unwrap().push([x, y]),
+            None if segments.last().is_some_and(|s| !s.is_empty()) => segments.push(Vec::new()),
+            None => {}
+        }
+    }
+    segments.retain(|s| !s.is_empty());
+    segments
+}
+
+/// The element picker, plot with its legend, and cursor readout for an open plot file
+pub fn show(ui: &mut egui::Ui, viewer: &mut PlotViewer) {
+    let PlotViewer { path, data, log_scale, elements } = viewer;
+    let data = match data {
+        Ok(data) => data,
+        Err(e) => {
+            ui.colored_label(Color32::LIGHT_RED, format!("Could not show {}: {}", path.display(), e));
+            return;
+        }
+    };
+
+    ui.label(&data.title);
+    let mut reset = false;
+    ui.horizontal(|ui| {
+        reset = ui.checkbox(log_scale, "Log scale").changed();
+        ui.weak("Click legend entries to hide series. Drag to pan, scroll to zoom, double-click to reset");
+    });
+    egui::CollapsingHeader::new(format!("X-ray lines ({} marked)", elements.len())).id_salt("xray_lines").show(ui, |ui| {
+        if !data.is_energy() {
+            ui.colored_label(Color32::YELLOW, "This plot is by channel, so lines can't be marked on it");
+        }
+        element_picker(ui, elements);
+        line_table(ui, elements);
+    });
+
+    let (is_energy, log_scale) = (data.is_energy(), *log_scale);
+    let readout_height = ui.text_style_height(&egui::TextStyle::Body) + ui.spacing().item_spacing.y;
+    let mut plot = Plot::new("plot_view")
+        .legend(Legend::default())
+        .x_axis_label(data.x_label.as_str())
+        .height((ui.available_height() - readout_height).max(150.0))
+        .label_formatter(move |name, value| {
+            let x = if is_energy { format!("{:.3} keV", value.x) } else { format!("channel {:.0}", value.x) };
+            let y = if log_scale { 10f64.powf(value.y) } else { value.y };
+            if name.is_empty() { x } else { format!("{}\n{}\n{}", name, x, format_number(y)) }
+        });
+    if log_scale {
+        plot = plot.y_axis_formatter(|mark, _| format_number(10f64.powf(mark.value)));
+    }
+    if reset {
+        plot = plot.reset();
+    }
+    let response = plot.show(ui, |plot_ui| {
+        for (index, series) in data.series.iter().enumerate() {
+            let color = COLORS[index % COLORS.len()];
+            for segment in segments(&data.x, &series.values, log_scale) {
+                plot_ui.line(Line::new(PlotPoints::from(segment)).name(&series.name).color(color));
+            }
+        }
+        if is_energy {
+            for (index, line) in markers(data, elements) {
+                let color = MARKER_COLORS[index % MARKER_COLORS.len()];
+                plot_ui.vline(VLine::new(line.energy as f64 / 1000.0).color(color.gamma_multiply(0.3 + 0.7 * line.relative)));
+            }
+        }
+        plot_ui.pointer_coordinate()
+    });
+    if is_energy {
+        label_markers(ui, &response.transform, data, elements);
+    }
+
+    // Cursor readout: the axis value under the pointer and each series there
+    let readout = response.inner.and_then(|pointer: PlotPoint| {
+        let row = data.nearest(pointer.x)?;
+        let mut text = if is_energy { format!("{:.3} keV", data.x[row]) } else { format!("channel {}", data.x[row]) };
+        for series in &data.series {
+            text.push_str(&format!("   {} {}", series.name, format_number(series.values[row])));
+        }
+        Some(text)
+    });
+    match readout {
+        Some(text) => ui.monospace(text),
+        None => ui.weak(format!("{}: point at the plot to read values", data.x_label))
+    };
+}
+
+// Periodic table of toggles for marking elements; elements PIQUANT has no lines for are greyed out
+fn element_picker(ui: &mut egui::Ui, elements: &mut Vec<u8>) {
+    let mut table = [[None; 18]; 9];
+    for z in 1..=xray_lines::SYMBOLS.len() as u8 {
+        let (row, column) = xray_lines::position(z);
+        table[row][column] = Some(z);
+    }
+    egui::Grid::new("periodic_table").spacing([2.0, 2.0]).show(ui, |ui| {
+        for row in table {
+            for cell in row {
+                let Some(z) = cell else {
+                    ui.label("");
+                    continue;
+                };
+                let marked = elements.contains(&z);
+                let has_lines = xray_lines::lines(z).next().is_some();
+                let response = ui.add_enabled_ui(has_lines, |ui| {
+                    ui.add_sized([26.0, 18.0], egui::SelectableLabel::new(marked, xray_lines::symbol(z)))
+                }).inner;
+                let response = response.on_hover_ui(|ui| {
+                    ui.label(format!("{} (Z = {})", xray_lines::symbol(z), z));
+                    for line in xray_lines::lines(z).filter(|l| l.relative >= 0.1) {
+                        ui.label(format!("{} {:.3} keV", line.siegbahn, line.energy / 1000.0));
+                    }
+                });
+                if response.clicked() {
+                    if marked {
+                        elements.retain(|e| *e != z);
+                    } else {
+                        elements.push(z);
+                    }
+                }
+            }
+            ui.end_row();
+        }
+    });
+}
+
+// Energies and intensities of the lines of the marked elements
+fn line_table(ui: &mut egui::Ui, elements: &mut Vec<u8>) {
+    if elements.is_empty() {
+        ui.weak("Pick elements above to mark their lines.");
+        return;
+    }
+    ui.horizontal(|ui| {
+        ui.weak("Intensities are relative to the other lines filling the same vacancy.");
+        if ui.button("Clear").clicked() {
+            elements.clear();
+        }
+    });
+    egui::ScrollArea::vertical().id_salt("line_table").max_height(160.0).show(ui, |ui| {
+        egui::Grid::new("line_table_grid").striped(true).num_columns(5).show(ui, |ui| {
+            for heading in ["Element", "Line", "IUPAC", "Energy (keV)", "Relative"] {
+                ui.strong(heading);
+            }
+            ui.end_row();
+            for (index, &z) in elements.iter().enumerate() {
+                let color = MARKER_COLORS[index % MARKER_COLORS.len()];
+                for line in xray_lines::lines(z) {
+                    ui.colored_label(color, xray_lines::symbol(z));
+                    ui.label(line.siegbahn);
+                    ui.label(line.iupac);
+                    ui.label(format!("{:.4}", line.energy / 1000.0));
+                    ui.label(format!("{:.4}", line.relative));
+                    ui.end_row();
+                }
+            }
+        });
+    });
+}
+
+// Lines of the marked elements strong enough for a marker and within the plot's energy range,
+// with the index of the element they belong to. Markers outside would widen the plot
+fn markers<'a>(data: &PlotData, elements: &'a [u8]) -> impl Iterator<Item = (usize, &'static xray_lines::Line)> + 'a {
+    let (low, high) = (data.x[0].min(data.x[data.x.len() - 1]), data.x[0].max(data.x[data.x.len() - 1]));
+    elements.iter().enumerate().flat_map(move |(index, &z)| {
+        xray_lines::lines(z)
+            .filter(move |l| l.relative >= MARKER_MIN && (low..=high).contains(&(l.energy as f64 / 1000.0)))
+            .map(move |l| (index, l))
+    })
+}
+
+// Label the markers along the top of the plot, one row per element. Only the strongest of lines
+// closer together than LABEL_SPACING is labelled
+fn label_markers(ui: &egui::Ui, transform: &PlotTransform, data: &PlotData, elements: &[u8]) {
+    let frame = *transform.frame();
+    let painter = ui.painter().with_clip_rect(frame);
+    let font = egui::FontId::proportional(11.0);
+    let mut lines: Vec<(usize, &xray_lines::Line)> = markers(data, elements).collect();
+    lines.sort_by(|a, b| b.1.relative.total_cmp(&a.1.relative));
+    let mut labelled: Vec<(usize, f32)> = Vec::new();
+    for (index, line) in lines {
+        let x = transform.position_from_point_x(line.energy as f64 / 1000.0);
+        if labelled.iter().any(|&(i, l)| i == index && (l - x).abs() < LABEL_SPACING) {
+            continue;
+        }
+        labelled.push((index, x));
+        let color = MARKER_COLORS[index % MARKER_COLORS.len()];
+        let position = Pos2::new(x + 2.0, frame.top() + 2.0 + 14.0 * (index % 4) as f32);
+        let text = format!("{} {}", xray_lines::symbol(elements[index]), line.siegbahn);
+        painter.line_segment([Pos2::new(x, position.y), Pos2::new(x, position.y + 12.0)], Stroke::new(2.0, color));
+        painter.text(position, egui::Align2::LEFT_TOP, text, font.clone(), color);
+    }
+}
+
+// Numbers for the y axis and readout, without needless digits
+fn format_number(value: f64) -> String {
+    let magnitude = value.abs();
+    if magnitude != 0.0 && !(1e-3..1e6).contains(&magnitude) {
+        format!("{:.2e}", value)
+    } else if magnitude >= 100.0 || value.fract() == 0.0 {
+        format!("{:.0}", value)
+    } else {
+        format!("{:.3}", value).trim_end_matches('0').trim_end_matches('.').to_string()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const PLOT: &str = "\
+   PIQUANT 4.0.6  bhvo.msa
+Energy (keV), meas, calc, bkg, sigma, residual, Fe K
+0.01, 3, 2.5, 1, 1.7, 0.3, 1
+0.02, 5, 4.5, 1.2, 2.2, 0.2, 1.2
+0.03, 0, 0.5, 1.1, 0, -0.4, 1.1
+";
+
+    #[test]
+    fn plot_file() {
+        let data = parse(PLOT).unwrap();
+        assert_eq!(data.title, "PIQUANT 4.0.6  bhvo.msa");
+        assert!(data.is_energy());
+        assert_eq!(data.series.len(), 6);
+        assert_eq!(data.series[5].name, "Fe K");
+        assert_eq!(data.series[0].values, [3.0, 5.0, 0.0]);
+        assert_eq!(data.nearest(0.024), Some(1));
+        assert_eq!(data.nearest(1.0), Some(2));
+        assert_eq!(data.nearest(-1.0), Some(0));
+
+        assert!(parse("title\nChannel, meas\n1, 2, 3\n").is_err());
+        assert!(parse("title\nChannel, meas\n").is_err());
+    }
+
+    #[test]
+    fn line_segments() {
+        let data = parse(PLOT).unwrap();
+        assert_eq!(segments(&data.x, &data.series[0].values, false).len(), 1);
+        // The zero count can't go on a log scale
+        assert_eq!(segments(&data.x, &data.series[0].values, true), [vec![[0.01, 3f64.log10()], [0.02, 5f64.log10()]]]);
+        assert_eq!(segments(&[1.0, 2.0, 3.0, 4.0], &[1.0, f64::NAN, 2.0, 3.0], false), [vec![[1.0, 1.0]], vec![[3.0, 2.0], [4.0, 3.0]]]);
+        assert_eq!(format_number(10f64.powf(2.0)), "100");
+        assert_eq!(format_number(0.25), "0.25");
+    }
+
+    #[test]
+    fn markers_stay_in_range() {
+        let data = parse(PLOT).unwrap();
+        assert_eq!(markers(&data, &[26]).count(), 0);
+        let data = parse("title\nEnergy (keV), meas\n0.5, 1\n10, 2\n").unwrap();
+        let elements = [26, 82];
+        let marked: Vec<(usize, &str)> = markers(&data, &elements).map(|(i, l)| (i, l.siegbahn)).collect();
+        assert!(marked.contains(&(0, "Ka1")) && marked.contains(&(1, "Ma")));
+        assert!(!marked.contains(&(1, "Ka1")));
+    }
+}
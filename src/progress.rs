@@ -0,0 +1,222 @@
+// Description: progress tracking for Map and Bulk Sum runs, which process one spectrum file after another.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::{Duration, Instant};
+
+use crate::gui;
+
+// PIQUANT stops looking for more spectrum files after this many, see PIQUANT_CommandLine.cpp
+const MAX_MAP_SPECTRA: usize = 1_000_000;
+
+// Comment marker in spectrum file lists (COMMENT_STRING in XRFconstants.h)
+const COMMENT_STRING: &str = "//";
+
+/// Spectra processed so far in a Map or Bulk Sum run
+pub struct Progress {
+    task: gui::Tasks,
+    pub total: usize,
+    pub done: usize,
+    started: Instant
+}
+
+impl Progress {
+    /// Set up progress tracking for a run, if the task processes several spectra and their number can be found
+    pub fn new(task: gui::Tasks, spectrum_file: &Path, args: &[String]) -> Option<Progress> {
+        if !matches!(task, gui::Tasks::Map | gui::Tasks::BulkSumAndMaxValue) {
+            return None;
+        }
+        let max_arg = max_map_arg(args);
+        let total = match (count_map_spectra(spectrum_file), max_arg) {
+            (Some(count), Some(max)) => count.min(max),
+            (Some(count), None) => count,
+            (None, Some(max)) => max,
+            (None, None) => return None
+        };
+        Some(Progress {
+            task,
+            total,
+            done: 0,
+            started: Instant::now()
+        })
+    }
+
+    /// Count a line of PIQUANT output if it marks a spectrum as processed
+    pub fn update(&mut self, line: &str) {
+        let finished_spectrum = match self.task {
+            // Written by map_threading.cpp as each worker thread finishes a spectrum
+            gui::Tasks::Map => line.contains(" Job ran: "),
+            // Written as spectrumBulkSumMax reads each spectrum: by read_spectrum_file.cpp, or
+            // read_PIXLISE_spectrum.cpp with a colon for .pmcs datasets
+            gui::Tasks::BulkSumAndMaxValue => ["Reading spectrum from file ", "Reading spectrum from file:"].iter().any(|m| line.starts_with(m)),
+            _ => false
+        };
+        if finished_spectrum && self.done < self.total {
+            self.done += 1;
+        }
+    }
+
+    /// Fraction of spectra processed, from 0 to 1
+    pub fn fraction(&self) -> f32 {
+        if self.total == 0 {
+            return 0.0;
+        }
+        self.done as f32 / self.total as f32
+    }
+
+    /// Estimated time left, from the average time per spectrum so far
+    pub fn eta(&self) -> Option<Duration> {
+        if self.done == 0 {
+            return None;
+        }
+        let per_spectrum = self.started.elapsed().as_secs_f64() / self.done as f64;
+        Some(Duration::from_secs_f64(per_spectrum * (self.total - self.done) as f64))
+    }
+}
+
+/// Value of the -m option (maximum number of spectrum files to read), if given; as in parse_arguments.cpp
+/// the last one counts, and zero means no limit
+fn max_map_arg(args: &[String]) -> Option<usize> {
+    args.iter()
+        .rev()
+        .filter_map(|a| a.strip_prefix("-m,"))
+        .find_map(|v| v.trim().parse::<usize>().ok())
+        .filter(|&v| v > 0)
+}
+
+/// Count the spectra PIQUANT will read for a map input, following the rules in PIQUANT_CommandLine.cpp
+fn count_map_spectra(spectrum_file: &Path) -> Option<usize> {
+    let extension = spectrum_file.extension()?.to_str()?.to_ascii_lowercase();
+    match extension.as_str() {
+        // List of spectrum file names, skipping blank lines and comments
+        "txt" => {
+            let contents = fs::read_to_string(spectrum_file).ok()?;
+            Some(contents.lines()
+                .map(str::trim)
+                .filter(|l| l.len() >= 2 && !l.starts_with(COMMENT_STRING))
+                .count())
+        }
+        // PIXLISE dataset: binary file name on the first line, then one PMC per line
+        "pmcs" => {
+            let contents = fs::read_to_string(spectrum_file).ok()?;
+            Some(contents.lines()
+                .skip(1)
+                .take_while(|l| !l.is_empty())
+                .count())
+        }
+        // Otherwise the first of a run of files with incrementing sequence numbers
+        _ => count_sequence_files(spectrum_file)
+    }
+}
+
+/// Count files numbered in sequence after the given one, e.g. scan_001.msa, scan_002.msa, ...
+fn count_sequence_files(first: &Path) -> Option<usize> {
+    if !first.is_file() {
+        return None;
+    }
+    let mut count = 1;
+    let mut current = first.to_path_buf();
+    while count < MAX_MAP_SPECTRA {
+        match increment_sequence(&current) {
+            Some(next) if next.is_file() => {
+                current = next;
+                count += 1;
+            }
+            _ => break
+        }
+    }
+    Some(count)
+}
+
+/// Next file name in a sequence, taking the number between the last underscore and the extension (map_spectrum_file_increment.cpp)
+fn increment_sequence(path: &Path) -> Option<PathBuf> {
+    let name = path.file_name()?.to_str()?;
+    let start = name.rfind('_')? + 1;
+    let end = name.rfind('.')?;
+    if end <= start {
+        return None;
+    }
+    let number: u64 = name[start..end].parse().ok()?;
+    Some(path.with_file_name(format!("{}{}{}", &name[..start], number + 1, &name[end..])))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    // A fresh directory for one test's files
+    fn test_dir(name: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("piquant-gui-progress-{}-{}", name, std::process::id()));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn args(values: &[&str]) -> Vec<String> {
+        values.iter().map(|v| v.to_string()).collect()
+    }
+
+    #[test]
+    fn max_spectra_option() {
+        assert_eq!(max_map_arg(&args(&["map", "a.msa", "-m,25"])), Some(25));
+        assert_eq!(max_map_arg(&args(&["map", "-m,0", "-m, 7"])), Some(7));
+        // PIQUANT keeps the last -m it's given
+        assert_eq!(max_map_arg(&args(&["map", "-m,25", "-m,10"])), Some(10));
+        assert_eq!(max_map_arg(&args(&["map", "-m,25", "-m,0"])), None);
+        assert_eq!(max_map_arg(&args(&["map", "-m,many"])), None);
+        assert_eq!(max_map_arg(&args(&["map", "a.msa"])), None);
+    }
+
+    #[test]
+    fn spectrum_lists() {
+        let dir = test_dir("lists");
+        let list = dir.join("spectra.txt");
+        fs::write(&list, "a.msa\n// skipped\n\nb.msa\nc.msa\n").unwrap();
+        assert_eq!(count_map_spectra(&list), Some(3));
+        let dataset = dir.join("scan.pmcs");
+        fs::write(&dataset, "scan.bin\n12\n13\n14\n\n99\n").unwrap();
+        assert_eq!(count_map_spectra(&dataset), Some(3));
+        assert_eq!(count_map_spectra(&dir.join("missing.txt")), None);
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn numbered_sequences() {
+        assert_eq!(increment_sequence(Path::new("/data/scan_9.msa")), Some(PathBuf::from("/data/scan_10.msa")));
+        // PIQUANT doesn't keep leading zeros
+        assert_eq!(increment_sequence(Path::new("scan_099.msa")), Some(PathBuf::from("scan_100.msa")));
+        assert_eq!(increment_sequence(Path::new("scan.msa")), None);
+        assert_eq!(increment_sequence(Path::new("scan_x.msa")), None);
+
+        let dir = test_dir("sequence");
+        for name in ["scan_8.msa", "scan_9.msa", "scan_10.msa", "scan_12.msa"] {
+            fs::write(dir.join(name), "").unwrap();
+        }
+        assert_eq!(count_sequence_files(&dir.join("scan_8.msa")), Some(3));
+        assert_eq!(count_sequence_files(&dir.join("scan_7.msa")), None);
+        let progress = Progress::new(gui::Tasks::Map, &dir.join("scan_8.msa"), &args(&["map", "-m,2"])).unwrap();
+        assert_eq!(progress.total, 2);
+        let progress = Progress::new(gui::Tasks::Map, &dir.join("scan_8.msa"), &args(&["map", "-m,10"])).unwrap();
+        assert_eq!(progress.total, 3);
+        assert!(Progress::new(gui::Tasks::Quantify, &dir.join("scan_8.msa"), &[]).is_none());
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn output_markers() {
+        let mut map = Progress::new(gui::Tasks::Map, Path::new("missing.msa"), &args(&["-m,2"])).unwrap();
+        map.update("Thread 1  Job ran: 3");
+        map.update("Reading spectrum from file a.msa");
+        assert_eq!(map.done, 1);
+        map.update("Thread 2  Job ran: 4");
+        map.update("Thread 1  Job ran: 5");
+        assert_eq!((map.done, map.fraction()), (2, 1.0));
+
+        let mut sum = Progress::new(gui::Tasks::BulkSumAndMaxValue, Path::new("missing.msa"), &args(&["-m,3"])).unwrap();
+        sum.update("Reading spectrum from file a.msa");
+        sum.update("Reading spectrum from file: scan.bin with selector: 12");
+        sum.update("Thread 1  Job ran: 3");
+        assert_eq!(sum.done, 2);
+        assert!(sum.eta().is_some());
+    }
+}
@@ -0,0 +1,225 @@
+// Description: queue of PIQUANT jobs that are run one after another.
+
+use crate::schema;
+use crate::runner;
+
+/// A snapshot of a task and its arguments, waiting in or run from the queue
+pub struct QueuedJob {
+    pub id: u64,
+    pub form: schema::Form,
+    pub args: Vec<String>,
+    // Idle while waiting to run
+    pub state: runner::JobState,
+    pub exit_code: Option<i32>
+}
+
+#[derive(Default)]
+pub struct JobQueue {
+    pub jobs: Vec<QueuedJob>,
+    // Whether queued jobs should be started as soon as nothing else is running
+    pub running: bool,
+    // ID of the queued job that is currently running
+    pub active: Option<u64>,
+    // When set, only these jobs are run, after which the queue stops
+    pub only: Option<Vec<u64>>,
+    next_id: u64
+}
+
+impl JobQueue {
+    /// Add a job to the end of the queue, returning its ID
+    pub fn push(&mut self, form: schema::Form, args: Vec<String>) -> u64 {
+        self.jobs.push(QueuedJob {
+            id: self.next_id,
+            form,
+            args,
+            state: runner::JobState::Idle,
+            exit_code: None
+        });
+        self.next_id += 1;
+        self.next_id - 1
+    }
+
+    /// Run the given jobs, leaving anything else waiting in the queue alone
+    pub fn run_only(&mut self, ids: Vec<u64>) {
+        match &mut self.only {
+            Some(only) => only.extend(ids),
+            // Already running everything
+            None if self.running => {}
+            None => self.only = Some(ids)
+        }
+        self.running = true;
+    }
+
+    /// Run every waiting job
+    pub fn run_all(&mut self) {
+        self.only = None;
+        self.running = true;
+    }
+
+    /// Swap the job at index with the one above it
+    pub fn move_up(&mut self, index: usize) {
+        if index > 0 && index < self.jobs.len() {
+            self.jobs.swap(index, index - 1);
+        }
+    }
+
+    /// Swap the job at index with the one below it
+    pub fn move_down(&mut self, index: usize) {
+        if index + 1 < self.jobs.len() {
+            self.jobs.swap(index, index + 1);
+        }
+    }
+
+    /// Remove a job, unless it is the one running
+    pub fn remove(&mut self, index: usize) {
+        if index < self.jobs.len() && Some(self.jobs[index].id) != self.active {
+            self.jobs.remove(index);
+        }
+    }
+
+    /// Put a finished, failed or cancelled job back in line
+    pub fn retry(&mut self, index: usize) {
+        if let Some(job) = self.jobs.get_mut(index) {
+            if Some(job.id) != self.active {
+                job.state = runner::JobState::Idle;
+                job.exit_code = None;
+            }
+        }
+    }
+
+    /// Remove every job that has finished successfully
+    pub fn clear_finished(&mut self) {
+        self.jobs.retain(|j| j.state != runner::JobState::Finished);
+    }
+
+    /// Mark the next waiting job as running and return its arguments and form, or stop the queue if none are left
+    pub fn start_next(&mut self) -> Option<(Vec<String>, schema::Form)> {
+        if !self.running || self.active.is_some() {
+            return None;
+        }
+        let only = &self.only;
+        let next = self.jobs.iter_mut()
+            .find(|j| j.state == runner::JobState::Idle && only.as_ref().is_none_or(|ids| ids.contains(&j.id)));
+        match next {
+            Some(job) => {
+                job.state = runner::JobState::Running;
+                self.active = Some(job.id);
+                Some((job.args.clone(), job.form.clone()))
+            }
+            None => {
+                self.running = false;
+                self.only = None;
+                None
+            }
+        }
+    }
+
+    /// Record how the running job ended
+    pub fn finish_active(&mut self, state: runner::JobState, exit_code: Option<i32>) {
+        if let Some(id) = self.active.take() {
+            if let Some(job) = self.jobs.iter_mut().find(|j| j.id == id) {
+                job.state = state;
+                job.exit_code = exit_code;
+            }
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use runner::JobState;
+
+    fn queue(count: usize) -> JobQueue {
+        let mut queue = JobQueue::default();
+        for job in 0..count {
+            queue.push(schema::Form::default(), vec![job.to_string()]);
+        }
+        queue
+    }
+
+    fn order(queue: &JobQueue) -> Vec<u64> {
+        queue.jobs.iter().map(|j| j.id).collect()
+    }
+
+    #[test]
+    fn moving_past_the_ends() {
+        let mut queue = queue(3);
+        queue.move_up(0);
+        queue.move_down(2);
+        queue.move_up(5);
+        queue.move_down(5);
+        assert_eq!(order(&queue), [0, 1, 2]);
+        queue.move_up(2);
+        queue.move_down(0);
+        assert_eq!(order(&queue), [2, 0, 1]);
+    }
+
+    #[test]
+    fn running_job_stays() {
+        let mut queue = queue(2);
+        queue.running = true;
+        assert_eq!(queue.start_next().map(|(args, _)| args), Some(vec![String::from("0")]));
+        // Only one job runs at a time
+        assert!(queue.start_next().is_none());
+        queue.remove(0);
+        queue.retry(0);
+        assert_eq!(order(&queue), [0, 1]);
+        assert_eq!(queue.jobs[0].state, JobState::Running);
+
+        queue.finish_active(JobState::Failed, Some(1));
+        assert_eq!((queue.jobs[0].state, queue.jobs[0].exit_code), (JobState::Failed, Some(1)));
+        queue.remove(0);
+        assert_eq!(order(&queue), [1]);
+        queue.remove(3);
+        assert_eq!(order(&queue), [1]);
+    }
+
+    #[test]
+    fn retry_and_clear() {
+        let mut queue = queue(3);
+        queue.running = true;
+        for state in [JobState::Finished, JobState::Cancelled] {
+            queue.start_next();
+            queue.finish_active(state, None);
+        }
+        // A job still waiting is left as it is
+        queue.retry(2);
+        queue.retry(7);
+        assert_eq!(queue.jobs[2].state, JobState::Idle);
+        queue.retry(1);
+        assert_eq!(queue.jobs[1].state, JobState::Idle);
+        queue.clear_finished();
+        assert_eq!(order(&queue), [1, 2]);
+    }
+
+    #[test]
+    fn only_the_runs_asked_for() {
+        let mut queue = queue(2);
+        let split = [queue.push(schema::Form::default(), vec![String::from("A")]), queue.push(schema::Form::default(), vec![String::from("B")])];
+        queue.run_only(split.to_vec());
+        for expected in ["A", "B"] {
+            assert_eq!(queue.start_next().map(|(args, _)| args), Some(vec![String::from(expected)]));
+            queue.finish_active(JobState::Finished, Some(0));
+        }
+        assert!(queue.start_next().is_none());
+        assert!(!queue.running && queue.only.is_none());
+        // Jobs that were already waiting are left for the user to start
+        assert_eq!(queue.jobs[0].state, JobState::Idle);
+        assert_eq!(queue.jobs[1].state, JobState::Idle);
+        queue.run_all();
+        assert_eq!(queue.start_next().map(|(args, _)| args), Some(vec![String::from("0")]));
+    }
+
+    #[test]
+    fn empty_queue_stops() {
+        let mut queue = JobQueue::default();
+        assert!(queue.start_next().is_none());
+        queue.running = true;
+        assert!(queue.start_next().is_none());
+        assert!(!queue.running);
+        // Finishing with nothing active changes nothing
+        queue.finish_active(JobState::Finished, Some(0));
+        assert!(queue.active.is_none());
+    }
+}
@@ -0,0 +1,504 @@
+// Description: functions to run PIQUANT on a background worker thread and track the job.
+
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::ffi::OsStr;
+use std::fs::{self, File};
+use std::io::{self, BufRead, BufReader, Read, Seek, SeekFrom};
+use std::path::{Path, PathBuf};
+use std::process::{Command, ExitStatus, Stdio};
+use std::sync::atomic::{AtomicBool, AtomicU32, Ordering};
+use std::sync::mpsc::{self, Receiver, Sender, TryRecvError};
+use std::sync::Arc;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Environment variable that can point at the PIQUANT executable
+pub const PIQUANT_PATH_VAR: &str = "PIQUANT_PATH";
+
+/// Where the PIQUANT executable path came from, in priority order
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum PathSource {
+    CommandLine,
+    Environment,
+    Settings,
+    SearchPath,
+    Default
+}
+
+impl PathSource {
+    /// Short description for the settings panel
+    pub fn label(&self) -> &'static str {
+        match self {
+            PathSource::CommandLine => "--piquant flag",
+            PathSource::Environment => "PIQUANT_PATH environment variable",
+            PathSource::Settings => "settings",
+            PathSource::SearchPath => "PATH",
+            PathSource::Default => "bundled default"
+        }
+    }
+}
+
+/// How long PIQUANT is given to exit after a cancel before it is killed outright
+const CANCEL_GRACE_PERIOD: Duration = Duration::from_secs(3);
+
+/// How often the log file is checked for new output while PIQUANT runs
+const LOG_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// State of the most recent PIQUANT job
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub enum JobState {
+    Idle,
+    Running,
+    Finished,
+    Failed,
+    Cancelled
+}
+
+/// Messages sent from the worker thread while PIQUANT runs
+#[derive(Debug)]
+pub enum JobEvent {
+    Stdout(String),
+    Stderr(String),
+    // A stream contained bytes that aren't UTF-8; sent once per stream, the line itself is still sent lossily
+    InvalidUtf8(&'static str),
+    Exited(io::Result<ExitStatus>)
+}
+
+/// A PIQUANT process running on a worker thread
+pub struct Job {
+    started: Instant,
+    receiver: Receiver<JobEvent>,
+    // Process ID of PIQUANT once started, 0 until then
+    pid: Arc<AtomicU32>,
+    cancelled: Arc<AtomicBool>,
+    exited: Arc<AtomicBool>
+}
+
+impl Job {
+    /// Start PIQUANT with the given arguments on a worker thread, in working_dir if given.
+    /// With a log file, PIQUANT sends its terminal output there instead, so the file is followed as stdout
+    pub fn spawn(exe: PathBuf, args: Vec<String>, working_dir: Option<PathBuf>, log_file: Option<PathBuf>) -> Job {
+        let (sender, receiver) = mpsc::channel();
+        // PIQUANT appends to the log, so only what is added from here on belongs to this run
+        let tail = log_file.map(LogTail::new);
+        let pid = Arc::new(AtomicU32::new(0));
+        let cancelled = Arc::new(AtomicBool::new(false));
+        let exited = Arc::new(AtomicBool::new(false));
+        let (worker_pid, worker_cancelled, worker_exited) = (pid.clone(), cancelled.clone(), exited.clone());
+
+        thread::spawn(move || {
+            let mut command = Command::new(&exe);
+            command.args(args)
+                .stdout(Stdio::piped())
+                .stderr(Stdio::piped());
+            if let Some(dir) = working_dir {
+                command.current_dir(dir);
+            }
+            // Start PIQUANT in its own process group, so cancelling also reaches anything it starts
+            #[cfg(unix)]
+            std::os::unix::process::CommandExt::process_group(&mut command, 0);
+
+            let mut child = match command.spawn() {
+                Ok(child) => child,
+                Err(e) => {
+                    worker_exited.store(true, Ordering::SeqCst);
+                    let e = io::Error::new(e.kind(), format!("could not start {}: {}", exe.display(), e));
+                    let _ = sender.send(JobEvent::Exited(Err(e)));
+                    return;
+                }
+            };
+            worker_pid.store(child.id(), Ordering::SeqCst);
+            // Cancel was pressed before PIQUANT had started
+            if worker_cancelled.load(Ordering::SeqCst) {
+                kill_tree(child.id(), false);
+            }
+
+            let tail_done = Arc::new(AtomicBool::new(false));
+            let tail_reader = tail.map(|mut tail| {
+                let (tail_sender, done) = (sender.clone(), tail_done.clone());
+                thread::spawn(move || loop {
+                    let finished = done.load(Ordering::SeqCst);
+                    for line in tail.read(finished) {
+                        let _ = tail_sender.send(JobEvent::Stdout(line));
+                    }
+                    if finished {
+                        break;
+                    }
+                    thread::sleep(LOG_POLL_INTERVAL);
+                })
+            });
+
+            // Read stderr on its own thread so neither pipe can fill up and stall PIQUANT
+            let stderr = child.stderr.take().unwrap();
+            let stderr_sender = sender.clone();
+            let stderr_reader = thread::spawn(move || read_lines(stderr, "stderr", &stderr_sender, JobEvent::Stderr));
+            let stdout = child.stdout.take().unwrap();
+            read_lines(stdout, "stdout", &sender, JobEvent::Stdout);
+            let _ = stderr_reader.join();
+
+            // The receiver is gone if the app closed mid-run; nothing left to report to.
+            let status = child.wait();
+            // Pick up the last of the log before reporting the exit
+            tail_done.store(true, Ordering::SeqCst);
+            if let Some(reader) = tail_reader {
+                let _ = reader.join();
+            }
+            worker_exited.store(true, Ordering::SeqCst);
+            let _ = sender.send(JobEvent::Exited(status));
+        });
+
+        Job {
+            started: Instant::now(),
+            receiver,
+            pid,
+            cancelled,
+            exited
+        }
+    }
+
+    /// Ask PIQUANT and its child processes to stop, forcing them if they haven't after a grace period
+    pub fn cancel(&self) {
+        if self.cancelled.swap(true, Ordering::SeqCst) {
+            return;
+        }
+        let pid = self.pid.load(Ordering::SeqCst);
+        if pid == 0 {
+            // Not started yet; the worker kills it as soon as it is
+            return;
+        }
+        kill_tree(pid, false);
+
+        let exited = self.exited.clone();
+        thread::spawn(move || {
+            thread::sleep(CANCEL_GRACE_PERIOD);
+            if !exited.load(Ordering::SeqCst) {
+                kill_tree(pid, true);
+            }
+        });
+    }
+
+    /// Whether the user cancelled this job
+    pub fn is_cancelled(&self) -> bool {
+        self.cancelled.load(Ordering::SeqCst)
+    }
+
+    /// Time since the job was started
+    pub fn elapsed(&self) -> Duration {
+        self.started.elapsed()
+    }
+
+    /// Collect everything the worker has sent since the last poll, without blocking the UI
+    pub fn poll(&self) -> Vec<JobEvent> {
+        let mut events = Vec::new();
+        loop {
+            match self.receiver.try_recv() {
+                Ok(event) => events.push(event),
+                Err(TryRecvError::Empty) => break,
+                Err(TryRecvError::Disconnected) => {
+                    // The worker hangs up straight after reporting how PIQUANT exited
+                    if !events.iter().any(|e| matches!(e, JobEvent::Exited(_))) {
+                        events.push(JobEvent::Exited(Err(io::Error::other("worker thread exited unexpectedly"))));
+                    }
+                    break;
+                }
+            }
+        }
+        events
+    }
+}
+
+/// Follows the log file PIQUANT appends its terminal output to
+pub struct LogTail {
+    path: PathBuf,
+    offset: u64,
+    // Bytes of a line PIQUANT hasn't finished writing yet
+    partial: Vec<u8>
+}
+
+impl LogTail {
+    /// Start following a log file from its current end
+    pub fn new(path: PathBuf) -> LogTail {
+        let offset = fs::metadata(&path).map(|m| m.len()).unwrap_or(0);
+        LogTail { path, offset, partial: Vec::new() }
+    }
+
+    /// Lines added since the last read; once PIQUANT has finished, also a last line without a newline
+    pub fn read(&mut self, finished: bool) -> Vec<String> {
+        if let Ok(mut file) = File::open(&self.path) {
+            // Start again if the file was replaced with a shorter one
+            if file.metadata().is_ok_and(|m| m.len() < self.offset) {
+                self.offset = 0;
+            }
+            if file.seek(SeekFrom::Start(self.offset)).is_ok() {
+                if let Ok(read) = file.read_to_end(&mut self.partial) {
+                    self.offset += read as u64;
+                }
+            }
+        }
+        let mut lines = Vec::new();
+        while let Some(end) = self.partial.iter().position(|&b| b == b'\n') {
+            let line: Vec<u8> = self.partial.drain(..=end).collect();
+            lines.push(String::from_utf8_lossy(&line).trim_end_matches(['\r', '\n']).to_string());
+        }
+        if finished && !self.partial.is_empty() {
+            lines.push(String::from_utf8_lossy(&std::mem::take(&mut self.partial)).trim_end_matches('\r').to_string());
+        }
+        lines
+    }
+}
+
+/// Ask PIQUANT for its version on a worker thread; the result arrives on the returned channel
+pub fn query_version(exe: PathBuf) -> Receiver<Result<String, String>> {
+    let (sender, receiver) = mpsc::channel();
+    thread::spawn(move || {
+        let result = match Command::new(&exe).arg("ver").output() {
+            Ok(output) if output.status.success() => {
+                let stdout = String::from_utf8_lossy(&output.stdout);
+                match stdout.lines().map(str::trim).find(|l| !l.is_empty()) {
+                    Some(line) => Ok(line.to_string()),
+                    None => Err(String::from("PIQUANT printed no version"))
+                }
+            }
+            // Releases before the ver sub-command print their help and exit with an error
+            Ok(_) => Err(String::from("this PIQUANT does not support the ver sub-command")),
+            Err(e) => Err(format!("could not start {}: {}", exe.display(), e))
+        };
+        let _ = sender.send(result);
+    });
+    receiver
+}
+
+/// Terminate a process and its children
+#[cfg(unix)]
+fn kill_tree(pid: u32, force: bool) {
+    // PIQUANT leads its own process group, so signal the whole group
+    let signal = if force { "-KILL" } else { "-TERM" };
+    let _ = Command::new("kill")
+        .args([signal, "--", format!("-{}", pid).as_str()])
+        .status();
+}
+
+/// Terminate a process and its children
+#[cfg(windows)]
+fn kill_tree(pid: u32, _force: bool) {
+    // Windows has no gentle equivalent of SIGTERM for console processes, so always force
+    let _ = Command::new("taskkill")
+        .args(["/PID", pid.to_string().as_str(), "/T", "/F"])
+        .status();
+}
+
+/// Forward each line of a PIQUANT output stream to the UI as it arrives
+fn read_lines<R: Read>(stream: R, name: &'static str, sender: &Sender<JobEvent>, event: fn(String) -> JobEvent) {
+    let mut reader = BufReader::new(stream);
+    let mut buf = Vec::new();
+    let mut reported_invalid = false;
+    loop {
+        buf.clear();
+        match reader.read_until(b'\n', &mut buf) {
+            Ok(0) | Err(_) => break,
+            Ok(_) => {
+                if !reported_invalid && std::str::from_utf8(&buf).is_err() {
+                    reported_invalid = true;
+                    let _ = sender.send(JobEvent::InvalidUtf8(name));
+                }
+                let line = String::from_utf8_lossy(&buf).trim_end_matches(['\r', '\n']).to_string();
+                if sender.send(event(line)).is_err() {
+                    break;
+                }
+            }
+        }
+    }
+}
+
+/// Describe why a run failed, or None if it succeeded
+pub fn describe_failure(status: &ExitStatus) -> Option<String> {
+    if status.success() {
+        return None;
+    }
+    if let Some(code) = status.code() {
+        return Some(format!("PIQUANT exited with code {}", code));
+    }
+    #[cfg(unix)]
+    if let Some(signal) = std::os::unix::process::ExitStatusExt::signal(status) {
+        return Some(format!("PIQUANT was terminated by signal {}", signal));
+    }
+    Some(String::from("PIQUANT stopped without an exit code"))
+}
+
+/// Name of the PIQUANT executable on this platform
+pub fn piquant_exe_name() -> &'static str {
+    if cfg!(target_os = "windows") {
+        "PIQUANT.exe"
+    } else {
+        // (Likely) on *nix or macOS
+        "PIQUANT"
+    }
+}
+
+/// Find the PIQUANT executable: the --piquant flag, then PIQUANT_PATH, then settings, then PATH, then the bundled default
+pub fn find_piquant(cli_path: Option<&str>, settings_path: &str) -> io::Result<(PathBuf, PathSource)> {
+    let env_path = env::var_os(PIQUANT_PATH_VAR);
+    let search_path = env::var_os("PATH");
+    let (path, source) = locate_piquant(cli_path, env_path.as_deref(), settings_path, search_path.as_deref(), env::current_exe())?;
+    // Relative paths are taken from the GUI's directory, not from each run's working directory
+    Ok((std::path::absolute(path)?, source))
+}
+
+/// Apply the search order used by find_piquant, given the environment's values and the GUI's own path
+fn locate_piquant(
+    cli_path: Option<&str>,
+    env_path: Option<&OsStr>,
+    settings_path: &str,
+    search_path: Option<&OsStr>,
+    gui_exe: io::Result<PathBuf>
+) -> io::Result<(PathBuf, PathSource)> {
+    if let Some(path) = cli_path.filter(|p| !p.is_empty()) {
+        return Ok((PathBuf::from(path), PathSource::CommandLine));
+    }
+    if let Some(path) = env_path.filter(|p| !p.is_empty()) {
+        return Ok((PathBuf::from(path), PathSource::Environment));
+    }
+    if !settings_path.is_empty() {
+        return Ok((PathBuf::from(settings_path), PathSource::Settings));
+    }
+    if let Some(paths) = search_path {
+        for dir in env::split_paths(paths) {
+            let candidate = dir.join(piquant_exe_name());
+            if candidate.is_file() {
+                return Ok((candidate, PathSource::SearchPath));
+            }
+        }
+    }
+    Ok((default_piquant_path(gui_exe)?, PathSource::Default))
+}
+
+/// Check if a path points at a file we are allowed to execute
+pub fn is_executable(path: &Path) -> bool {
+    let Ok(metadata) = path.metadata() else {
+        return false;
+    };
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        metadata.is_file() && metadata.permissions().mode() & 0o111 != 0
+    }
+    #[cfg(not(unix))]
+    {
+        metadata.is_file()
+    }
+}
+
+/// Default location of the PIQUANT CLI executable, relative to this executable
+fn default_piquant_path(gui_exe: io::Result<PathBuf>) -> io::Result<PathBuf> {
+    let cwe = gui_exe.map_err(|e| io::Error::new(e.kind(), format!("could not find the GUI executable for the default path: {}", e)))?;
+    let cwd = cwe.parent().ok_or_else(|| io::Error::other("the GUI executable has no parent directory for the default path"))?;
+
+    // Relative to the executable path, the PIQUANT CLI exe should be (unless otherwise specified) in ../../lib/cli/bin/
+    Ok(cwd.join("../../lib/cli/bin").join(piquant_exe_name()))
+}
+
+/// Format an elapsed duration as mm:ss
+pub fn format_elapsed(elapsed: Duration) -> String {
+    let secs = elapsed.as_secs();
+    format!("{:02}:{:02}", secs / 60, secs % 60)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::script;
+
+    #[test]
+    fn piquant_search_order() {
+        let dir = env::temp_dir().join(format!("piquant-gui-locate-{}", std::process::id()));
+        let (empty, first, second) = (dir.join("empty"), dir.join("first"), dir.join("second"));
+        for bin in [&empty, &first, &second] {
+            fs::create_dir_all(bin).unwrap();
+        }
+        for bin in [&first, &second] {
+            script::write(&bin.join(piquant_exe_name()), "").unwrap();
+        }
+        let search = env::join_paths([&empty, &first, &second]).unwrap();
+        let nowhere = env::join_paths([&empty]).unwrap();
+        let gui = dir.join("target/release/piquant-gui");
+        let default = dir.join("target/release/../../lib/cli/bin").join(piquant_exe_name());
+        let found = |cli, env_path: Option<&str>, settings, search_path: &OsStr| {
+            locate_piquant(cli, env_path.map(OsStr::new), settings, Some(search_path), Ok(gui.clone())).unwrap()
+        };
+
+        let cases = [
+            (Some("/cli/PIQUANT"), Some("/env/PIQUANT"), "/settings/PIQUANT", search.as_os_str(), (PathBuf::from("/cli/PIQUANT"), PathSource::CommandLine)),
+            (Some(""), Some("/env/PIQUANT"), "/settings/PIQUANT", search.as_os_str(), (PathBuf::from("/env/PIQUANT"), PathSource::Environment)),
+            (None, Some(""), "/settings/PIQUANT", search.as_os_str(), (PathBuf::from("/settings/PIQUANT"), PathSource::Settings)),
+            // The first directory on PATH holding PIQUANT wins
+            (None, None, "", search.as_os_str(), (first.join(piquant_exe_name()), PathSource::SearchPath)),
+            (None, None, "", nowhere.as_os_str(), (default.clone(), PathSource::Default)),
+            (None, None, "", OsStr::new(""), (default, PathSource::Default))
+        ];
+        for (cli, env_path, settings, search_path, expected) in cases {
+            assert_eq!(found(cli, env_path, settings, search_path), expected, "{:?} {:?} {:?}", cli, env_path, settings);
+        }
+
+        // The default needs the GUI's own location
+        let error = locate_piquant(None, None, "", None, Err(io::Error::other("no /proc"))).unwrap_err();
+        assert_eq!(error.to_string(), "could not find the GUI executable for the default path: no /proc");
+        let error = locate_piquant(None, None, "", None, Ok(PathBuf::from("/"))).unwrap_err();
+        assert_eq!(error.to_string(), "the GUI executable has no parent directory for the default path");
+        fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn failures_are_described() {
+        use std::os::unix::process::ExitStatusExt;
+        // Raw wait statuses: the exit code in the second byte, or the signal in the first
+        assert_eq!(describe_failure(&ExitStatus::from_raw(0)), None);
+        assert_eq!(describe_failure(&ExitStatus::from_raw(3 << 8)).as_deref(), Some("PIQUANT exited with code 3"));
+        assert_eq!(describe_failure(&ExitStatus::from_raw(9)).as_deref(), Some("PIQUANT was terminated by signal 9"));
+    }
+
+    #[test]
+    fn spawn_errors_are_reported() {
+        let exe = env::temp_dir().join("piquant-gui-missing").join(piquant_exe_name());
+        let job = Job::spawn(exe.clone(), Vec::new(), None, None);
+        let started = Instant::now();
+        let error = loop {
+            assert!(started.elapsed() < Duration::from_secs(10), "no exit event");
+            let mut events = job.poll().into_iter().filter(|e| matches!(e, JobEvent::Exited(_)));
+            if let Some(JobEvent::Exited(result)) = events.next() {
+                // Only the one exit, not also a lost worker
+                assert!(events.next().is_none());
+                break result.unwrap_err();
+            }
+            thread::sleep(Duration::from_millis(10));
+        };
+        assert_eq!(error.kind(), io::ErrorKind::NotFound);
+        assert!(error.to_string().starts_with(&format!("could not start {}: ", exe.display())), "{}", error);
+    }
+
+    #[test]
+    fn elapsed_times() {
+        assert_eq!(format_elapsed(Duration::ZERO), "00:00");
+        assert_eq!(format_elapsed(Duration::from_millis(59_999)), "00:59");
+        assert_eq!(format_elapsed(Duration::from_secs(61)), "01:01");
+        // Minutes keep counting past the hour
+        assert_eq!(format_elapsed(Duration::from_secs(2 * 3600 + 5)), "120:05");
+    }
+
+    #[test]
+    fn log_tail_follows_new_lines() {
+        let path = env::temp_dir().join(format!("piquant-gui-log-{}.txt", std::process::id()));
+        fs::write(&path, "earlier run\n").unwrap();
+        let mut tail = LogTail::new(path.clone());
+        assert!(tail.read(false).is_empty());
+
+        let mut file = fs::OpenOptions::new().append(true).open(&path).unwrap();
+        io::Write::write_all(&mut file, b"Energy calibration (-e,1.5,7.8)\r\nReading spec").unwrap();
+        assert_eq!(tail.read(false), ["Energy calibration (-e,1.5,7.8)"]);
+        io::Write::write_all(&mut file, b"trum from file a.msa\nDone").unwrap();
+        assert_eq!(tail.read(false), ["Reading spectrum from file a.msa"]);
+        assert_eq!(tail.read(true), ["Done"]);
+        fs::remove_file(&path).unwrap();
+    }
+}
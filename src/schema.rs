@@ -0,0 +1,992 @@
+// Description: the form fields each task uses, in the order PIQUANT expects them on its command line.
+// Enabling fields, checking them and building the argument list are all driven from the tables here.
+
+use serde::{Deserialize, Serialize};
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use crate::background;
+use crate::detector;
+use crate::energy_calibration;
+use crate::fit_options;
+use crate::functions::{self, FileFilter};
+use crate::gui::Tasks;
+use crate::map_outputs;
+
+/// Values entered in the form; also recorded with each run so it can be put back later
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Form {
+    pub task: Tasks,
+    pub working_dir: String,
+    pub config_file: String,
+    pub calib_file: String,
+    pub standards_file: String,
+    pub spectrum_file: String,
+    pub map_file: String,
+    pub plot_file: String,
+    pub log_file: String,
+    pub element_controls: String,
+    pub sdd_file: String,
+    pub edr_file: String,
+    pub cli_args: String,
+    pub background: background::Background,
+    pub detector: detector::Detector,
+    pub shelf: background::ParamValues,
+    pub map_outputs: map_outputs::MapOutputs,
+    pub fit: fit_options::FitOptions,
+    // -s value: a standard's number or name in the standards input file
+    pub standard: String,
+    pub energy: energy_calibration::EnergyCalibration,
+    // -u: evaluation file written by Calibrate, or plot file written by Evaluate
+    pub eval_file: String,
+    // -w: minimum weight of a standard's element for the evaluation output; blank for PIQUANT's 0.15
+    pub min_weight: String
+}
+
+impl Form {
+    /// Text entered for a field
+    pub fn value(&self, field: Field) -> &str {
+        match field {
+            Field::Config => &self.config_file,
+            Field::Calibration => &self.calib_file,
+            Field::Standards => &self.standards_file,
+            Field::Spectrum => &self.spectrum_file,
+            Field::Map => &self.map_file,
+            Field::ElementControls => &self.element_controls,
+            Field::Plot => &self.plot_file,
+            Field::Log => &self.log_file,
+            Field::SddData => &self.sdd_file,
+            Field::EdrFile => &self.edr_file
+        }
+    }
+
+    pub fn value_mut(&mut self, field: Field) -> &mut String {
+        match field {
+            Field::Config => &mut self.config_file,
+            Field::Calibration => &mut self.calib_file,
+            Field::Standards => &mut self.standards_file,
+            Field::Spectrum => &mut self.spectrum_file,
+            Field::Map => &mut self.map_file,
+            Field::ElementControls => &mut self.element_controls,
+            Field::Plot => &mut self.plot_file,
+            Field::Log => &mut self.log_file,
+            Field::SddData => &mut self.sdd_file,
+            Field::EdrFile => &mut self.edr_file
+        }
+    }
+}
+
+/// A field of the form that is passed to PIQUANT as a positional argument
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Field {
+    Config,
+    Calibration,
+    Standards,
+    Spectrum,
+    Map,
+    ElementControls,
+    Plot,
+    Log,
+    SddData,
+    EdrFile
+}
+
+impl Field {
+    // Fields holding file paths, in the order they appear in the form
+    pub const PATHS: [Field; 9] = [Field::Config, Field::Calibration, Field::Standards, Field::Spectrum, Field::Map, Field::SddData, Field::EdrFile, Field::Plot, Field::Log];
+
+    /// What the field holds, for hint text
+    pub fn name(&self) -> &'static str {
+        match self {
+            Field::Config => "configuration file",
+            Field::Calibration => "calibration file",
+            Field::Standards => "standards input file",
+            Field::Spectrum => "spectrum file",
+            Field::Map => "map file",
+            Field::ElementControls => "element fit controls",
+            Field::Plot => "plot file",
+            Field::Log => "log file",
+            Field::SddData => "SEND_SDD_DATA csv file",
+            Field::EdrFile => "EDR histogram csv file"
+        }
+    }
+
+    /// File types offered when browsing for the field, from the extensions PIQUANT checks for
+    pub fn filters(&self) -> &'static [FileFilter] {
+        match self {
+            Field::Config => &[FileFilter { name: "Configuration", extensions: &["msa", "xsp"] }],
+            Field::Calibration | Field::Standards => &[FileFilter { name: "Text or CSV", extensions: &["txt", "csv"] }],
+            Field::Spectrum => &[
+                FileFilter { name: "Spectra", extensions: &["msa", "mca", "mcs", "xsp"] },
+                // Map and Bulk Sum can also read a list of spectrum files, or a PIXLISE dataset
+                FileFilter { name: "Spectrum lists", extensions: &["txt", "pmcs"] }
+            ],
+            Field::Plot => &[FileFilter { name: "Plot", extensions: &["csv", "msa"] }],
+            Field::Log => &[FileFilter { name: "Log", extensions: &["txt", "log"] }],
+            Field::Map | Field::SddData | Field::EdrFile => &[FileFilter { name: "CSV", extensions: &["csv"] }],
+            Field::ElementControls => &[]
+        }
+    }
+
+    /// Label shown next to the field
+    pub fn label(&self) -> &'static str {
+        match self {
+            Field::Config => "Configuration file",
+            Field::Calibration => "Calibration file",
+            Field::Standards => "Standards input file",
+            Field::Spectrum => "Spectrum file",
+            Field::Map => "Map file",
+            Field::ElementControls => "Element fit controls",
+            Field::Plot => "Plot file",
+            Field::Log => "Log file (appends)",
+            Field::SddData => "SDD data file",
+            Field::EdrFile => "EDR output file"
+        }
+    }
+}
+
+/// Whether a field has to be filled in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Need {
+    Required,
+    // May be left empty, but PIQUANT still expects an argument in its place
+    Optional,
+    // May be left off the end of the command line altogether
+    Trailing
+}
+
+/// Whether PIQUANT reads the file, writes it or adds to it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Direction {
+    Input,
+    Output,
+    // Written to after anything already there, so never replaced
+    Append
+}
+
+/// How a task uses one field
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FieldSpec {
+    pub field: Field,
+    pub need: Need,
+    pub direction: Direction
+}
+
+/// The sub-command for a task, its fields in PIQUANT's argument order, and the options it uses
+#[derive(Debug)]
+pub struct TaskSpec {
+    pub sub_command: &'static str,
+    pub fields: &'static [FieldSpec],
+    pub options: &'static [&'static str]
+}
+
+const fn input(field: Field, need: Need) -> FieldSpec {
+    FieldSpec { field, need, direction: Direction::Input }
+}
+
+const fn output(field: Field, need: Need) -> FieldSpec {
+    FieldSpec { field, need, direction: Direction::Output }
+}
+
+// Every task takes a file to append its terminal output to, straight after its other files
+const LOG: FieldSpec = FieldSpec { field: Field::Log, need: Need::Trailing, direction: Direction::Append };
+
+// Fields taken from parse_arguments.cpp. Tasks that load measured spectra take the background and
+// detector options (setup_spectrum_parameters.cpp, quantCombineSpectra.cpp), and all but Energy
+// Calibration, which works the calibration out, can have it set with -e; those that read a
+// configuration file take -T (PIQUANT_CommandLine.cpp), and those writing map files take -q.
+// Fit switches go with the background; -c, -n and -Fe with the tasks that quantify. -s picks the
+// standard for the tasks that work on one; Calibrate ignores it and always uses every standard.
+// Calibrate and Evaluate can also write an extra file with -u, filtered by weight with -w
+const ENERGY_CALIBRATION: TaskSpec = TaskSpec {
+    sub_command: "ene",
+    fields: &[input(Field::Spectrum, Need::Required), input(Field::ElementControls, Need::Required), LOG],
+    options: &["-b", "-bh", "-bx", "-d", "-f", "-g", "-v"]
+};
+const PLOT_SPECTRUM: TaskSpec = TaskSpec {
+    sub_command: "plo",
+    fields: &[input(Field::Spectrum, Need::Required), output(Field::Plot, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-f", "-g", "-v"]
+};
+const CALCULATE_PRIMARY_SPECTRUM: TaskSpec = TaskSpec {
+    sub_command: "pri",
+    fields: &[input(Field::Config, Need::Required), output(Field::Plot, Need::Required), LOG],
+    options: &["-T"]
+};
+const CALCULATE_FULL_SPECTRUM: TaskSpec = TaskSpec {
+    sub_command: "calc",
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), output(Field::Plot, Need::Required), LOG],
+    options: &["-T", "-Fe", "-s"]
+};
+const COMPARE_MEASURED_CALCULATED: TaskSpec = TaskSpec {
+    sub_command: "com",
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), input(Field::Spectrum, Need::Required), output(Field::Plot, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-f", "-g", "-v", "-Fe", "-s"]
+};
+const OPTIC_RESPONSE: TaskSpec = TaskSpec {
+    sub_command: "opt",
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), input(Field::Spectrum, Need::Required), input(Field::ElementControls, Need::Optional), output(Field::Plot, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-f", "-g", "-v", "-c", "-Fe", "-s"]
+};
+const CALIBRATE: TaskSpec = TaskSpec {
+    sub_command: "cal",
+    // The calibration file is written, replacing any earlier one
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), output(Field::Calibration, Need::Required), input(Field::ElementControls, Need::Optional), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-q", "-f", "-g", "-v", "-c", "-Fe", "-u", "-w"]
+};
+const EVALUATE: TaskSpec = TaskSpec {
+    sub_command: "eva",
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), input(Field::Calibration, Need::Required), input(Field::ElementControls, Need::Optional), output(Field::Map, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-q", "-f", "-g", "-v", "-c", "-Fe", "-s", "-u", "-w"]
+};
+// Not listed in PIQUANT's help
+const FIT_ONE_STANDARD: TaskSpec = TaskSpec {
+    sub_command: "fits",
+    fields: &[input(Field::Config, Need::Required), input(Field::Standards, Need::Required), input(Field::ElementControls, Need::Optional), output(Field::Plot, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-f", "-g", "-v", "-c", "-Fe", "-s"]
+};
+const QUANTIFY: TaskSpec = TaskSpec {
+    sub_command: "qua",
+    fields: &[input(Field::Config, Need::Required), input(Field::Calibration, Need::Required), input(Field::Spectrum, Need::Required), input(Field::ElementControls, Need::Required), output(Field::Plot, Need::Trailing), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-f", "-g", "-v", "-c", "-n", "-Fe"]
+};
+const BULK_SUM_AND_MAX_VALUE: TaskSpec = TaskSpec {
+    sub_command: "sum",
+    fields: &[input(Field::Config, Need::Required), input(Field::Calibration, Need::Required), input(Field::Spectrum, Need::Required), input(Field::ElementControls, Need::Required), output(Field::Plot, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-f", "-g", "-v", "-c", "-n", "-Fe"]
+};
+const MAP: TaskSpec = TaskSpec {
+    sub_command: "map",
+    fields: &[input(Field::Config, Need::Required), input(Field::Calibration, Need::Required), input(Field::Spectrum, Need::Required), input(Field::ElementControls, Need::Required), output(Field::Map, Need::Required), LOG],
+    options: &["-e", "-b", "-bh", "-bx", "-d", "-T", "-q", "-f", "-g", "-v", "-c", "-n", "-Fe"]
+};
+// Converts SEND_SDD_DATA output into an EDR histogram
+const CONVERT_SDD_DATA: TaskSpec = TaskSpec {
+    sub_command: "ems",
+    fields: &[input(Field::SddData, Need::Required), output(Field::EdrFile, Need::Required), LOG],
+    options: &[]
+};
+
+/// Fields and sub-command for a task; None until a task is picked
+pub fn task_spec(task: Tasks) -> Option<&'static TaskSpec> {
+    match task {
+        Tasks::EnergyCalibration => Some(&ENERGY_CALIBRATION),
+        Tasks::PlotSpectrum => Some(&PLOT_SPECTRUM),
+        Tasks::CalculatePrimarySpectrum => Some(&CALCULATE_PRIMARY_SPECTRUM),
+        Tasks::CalculateFullSpectrum => Some(&CALCULATE_FULL_SPECTRUM),
+        Tasks::CompareMeasuredCalculated => Some(&COMPARE_MEASURED_CALCULATED),
+        Tasks::OpticResponse => Some(&OPTIC_RESPONSE),
+        Tasks::Calibrate => Some(&CALIBRATE),
+        Tasks::Evaluate => Some(&EVALUATE),
+        Tasks::FitOneStandardWithPlot => Some(&FIT_ONE_STANDARD),
+        Tasks::Quantify => Some(&QUANTIFY),
+        Tasks::BulkSumAndMaxValue => Some(&BULK_SUM_AND_MAX_VALUE),
+        Tasks::Map => Some(&MAP),
+        Tasks::ConvertSddData => Some(&CONVERT_SDD_DATA),
+        Tasks::None => None
+    }
+}
+
+impl TaskSpec {
+    /// How this task uses a field, or None if it doesn't
+    pub fn field(&self, field: Field) -> Option<&FieldSpec> {
+        self.fields.iter().find(|f| f.field == field)
+    }
+
+    /// Whether the task uses an option, e.g. "-b"
+    pub fn accepts(&self, option: &str) -> bool {
+        self.options.contains(&option)
+    }
+
+    /// Whether every field this task uses is filled in correctly, and its options are valid
+    pub fn ready(&self, form: &Form) -> bool {
+        self.fields.iter().all(|f| check_field(f, form.value(f.field), &form.working_dir))
+            && option_errors(form).is_empty()
+    }
+}
+
+/// Check a field's value: inputs must exist, outputs must go in an existing, writable directory
+pub fn check_field(spec: &FieldSpec, value: &str, working_dir: &str) -> bool {
+    if value.is_empty() {
+        return spec.need != Need::Required;
+    }
+    if spec.field == Field::ElementControls {
+        return true;
+    }
+    match spec.direction {
+        Direction::Input => functions::check_path(value.to_string(), working_dir),
+        Direction::Output | Direction::Append => output_dir_writable(value, working_dir)
+    }
+}
+
+/// Whether the directory an output file goes in exists and can be written to
+pub fn output_dir_writable(value: &str, working_dir: &str) -> bool {
+    let path = functions::resolve_path(value, working_dir);
+    let dir = match path.parent() {
+        Some(parent) if !parent.as_os_str().is_empty() => parent,
+        // A bare file name goes in the working directory
+        _ if working_dir.is_empty() => Path::new("."),
+        _ => Path::new(working_dir)
+    };
+    fs::metadata(dir).is_ok_and(|m| m.is_dir() && !m.permissions().readonly())
+}
+
+/// Result files that already exist and would be replaced by the form's runs; the log file is appended to, so isn't one
+pub fn existing_outputs(form: &Form) -> Vec<PathBuf> {
+    let mut existing: Vec<PathBuf> = Vec::new();
+    for run in runs(form) {
+        let Some(spec) = task_spec(run.task) else {
+            continue;
+        };
+        let mut outputs: Vec<&str> = spec.fields.iter()
+            .filter(|f| f.direction == Direction::Output)
+            .map(|f| run.value(f.field))
+            .collect();
+        if spec.accepts("-u") {
+            outputs.push(&run.eval_file);
+        }
+        for value in outputs.into_iter().filter(|v| !v.is_empty()) {
+            let path = functions::resolve_path(value, &run.working_dir);
+            if path.is_file() && !existing.contains(&path) {
+                existing.push(path);
+            }
+        }
+    }
+    existing
+}
+
+/// The log file a run sends PIQUANT's terminal output to, if any
+pub fn log_output(form: &Form) -> Option<PathBuf> {
+    let spec = task_spec(form.task)?;
+    if !spec.fields.contains(&LOG) || form.log_file.is_empty() {
+        return None;
+    }
+    Some(functions::resolve_path(&form.log_file, &form.working_dir))
+}
+
+/// The CSV plot file a run writes, if any. Plot files named .msa are written as spectra instead, and
+/// Evaluate writes its -u file as a plot when a single standard is picked (PIQUANT_CommandLine.cpp)
+pub fn plot_output(form: &Form) -> Option<PathBuf> {
+    let spec = task_spec(form.task)?;
+    let value = if spec.fields.iter().any(|f| f.field == Field::Plot && f.direction == Direction::Output) {
+        let spectrum = Path::new(&form.plot_file).extension().is_some_and(|e| e.eq_ignore_ascii_case("msa"));
+        if spectrum {
+            return None;
+        }
+        &form.plot_file
+    } else if form.task == Tasks::Evaluate && !form.standard.is_empty() {
+        &form.eval_file
+    } else {
+        return None;
+    };
+    (!value.is_empty()).then(|| functions::resolve_path(value, &form.working_dir))
+}
+
+/// Options set in the form that the selected task uses
+pub fn options(form: &Form) -> Vec<String> {
+    let Some(spec) = task_spec(form.task) else {
+        return Vec::new();
+    };
+    let mut options = Vec::new();
+    if spec.accepts("-e") {
+        options.extend(form.energy.arg());
+    }
+    options.extend(form.background.args(|option| spec.accepts(option)));
+    if spec.accepts("-d") {
+        options.extend(form.detector.arg());
+    }
+    if spec.accepts(detector::SHELF.option) {
+        options.extend(background::option_string(&detector::SHELF, &form.shelf));
+    }
+    if spec.accepts("-q") {
+        options.extend(form.map_outputs.arg());
+    }
+    options.extend(form.fit.args(|option| spec.accepts(option)));
+    if spec.accepts("-s") && !form.standard.is_empty() {
+        options.push(format!("-s,{}", form.standard));
+    }
+    if spec.accepts("-u") && !form.eval_file.is_empty() {
+        options.push(format!("-u,{}", form.eval_file));
+    }
+    if spec.accepts("-w") && !form.min_weight.trim().is_empty() {
+        options.push(format!("-w,{}", form.min_weight.trim()));
+    }
+    options
+}
+
+/// Problems with options the selected task uses
+pub fn option_errors(form: &Form) -> Vec<String> {
+    let Some(spec) = task_spec(form.task) else {
+        return Vec::new();
+    };
+    let mut errors = Vec::new();
+    if spec.accepts("-e") {
+        errors.extend(form.energy.errors());
+    }
+    errors.extend(form.background.errors(|option| spec.accepts(option)));
+    if spec.accepts(detector::SHELF.option) {
+        errors.extend(background::errors(&detector::SHELF, &form.shelf));
+    }
+    if spec.accepts("-q") {
+        errors.extend(form.map_outputs.errors());
+    }
+    errors.extend(form.fit.errors(&form.element_controls, |option| spec.accepts(option)));
+    if spec.accepts("-u") && !form.eval_file.is_empty() {
+        // PIQUANT splits options at commas
+        if form.eval_file.contains(',') {
+            errors.push(String::from("-u: PIQUANT can't take a file name containing a comma"));
+        } else if !output_dir_writable(&form.eval_file, &form.working_dir) {
+            errors.push(format!("-u: the directory for {} doesn't exist or can't be written to", form.eval_file));
+        }
+    }
+    if let Err(e) = functions::split_args(&form.cli_args) {
+        errors.push(format!("CLI arguments: {}", e));
+    }
+    if spec.accepts("-w") && !form.min_weight.trim().is_empty() {
+        let value = form.min_weight.trim();
+        if !value.parse::<f32>().is_ok_and(f32::is_finite) {
+            errors.push(format!("-w: \"{}\" is not a number", value));
+        }
+    }
+    errors
+}
+
+/// The runs a form stands for: usually one, or one per detector when they are run separately
+pub fn runs(form: &Form) -> Vec<Form> {
+    let split = task_spec(form.task)
+        .filter(|spec| spec.accepts("-d"))
+        .and_then(|_| form.detector.split());
+    let Some(split) = split else {
+        return vec![form.clone()];
+    };
+    split.iter().map(|(detector, suffix)| {
+        let mut run = form.clone();
+        run.detector = *detector;
+        // Keep each detector's results apart; the log file is appended to, so it can be shared
+        for spec in task_spec(form.task).into_iter().flat_map(|spec| spec.fields) {
+            if spec.direction == Direction::Output {
+                let path = run.value_mut(spec.field);
+                *path = functions::add_suffix(path, suffix);
+            }
+        }
+        if !run.eval_file.is_empty() {
+            run.eval_file = functions::add_suffix(&run.eval_file, suffix);
+        }
+        run
+    }).collect()
+}
+
+/// PIQUANT's arguments for a form, with its options and then anything typed in as CLI arguments
+pub fn args(form: &Form) -> Vec<String> {
+    let Some(spec) = task_spec(form.task) else {
+        return Vec::new();
+    };
+    // Carbonates chosen element by element are marked in the element list itself
+    let mut form = form.clone();
+    form.element_controls = form.fit.element_list(&form.element_controls, |option| spec.accepts(option));
+    let mut options = options(&form);
+    options.extend(functions::split_args(&form.cli_args).unwrap_or_default());
+    build_args(&form, &options)
+}
+
+/// Flags typed in as CLI arguments that are also set by the form's own controls, e.g. "-b"
+pub fn cli_clashes(form: &Form) -> Vec<String> {
+    let typed = functions::split_args(&form.cli_args).unwrap_or_default();
+    // Separate detector runs each set -d, so look at every run
+    let set: Vec<String> = runs(form).iter().flat_map(options).collect();
+    let mut clashes: Vec<String> = Vec::new();
+    for flag in typed.iter().filter(|a| a.starts_with('-')).map(|a| option_name(a)) {
+        if set.iter().any(|o| option_name(o) == flag) && !clashes.iter().any(|c| c == flag) {
+            clashes.push(flag.to_string());
+        }
+    }
+    clashes
+}
+
+// The option an argument sets: the text before its first comma
+fn option_name(arg: &str) -> &str {
+    arg.split(',').next().unwrap_or(arg)
+}
+
+/// Assemble PIQUANT's arguments: the sub-command, each field in order, then any options
+pub fn build_args(form: &Form, options: &[String]) -> Vec<String> {
+    let Some(spec) = task_spec(form.task) else {
+        return Vec::new();
+    };
+    let mut args = vec![spec.sub_command.to_string()];
+    args.extend(spec.fields.iter().map(|f| form.value(f.field).to_string()));
+
+    // Empty trailing fields can be dropped, as long as nothing comes after them
+    if options.is_empty() {
+        let mut trailing = spec.fields.iter().rev().take_while(|f| f.need == Need::Trailing);
+        while args.last().is_some_and(|a| a.is_empty()) && trailing.next().is_some() {
+            args.pop();
+        }
+    }
+    args.extend(options.iter().cloned());
+    args
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    const ALL_TASKS: [Tasks; 13] = [
+        Tasks::EnergyCalibration,
+        Tasks::PlotSpectrum,
+        Tasks::CalculatePrimarySpectrum,
+        Tasks::CalculateFullSpectrum,
+        Tasks::CompareMeasuredCalculated,
+        Tasks::OpticResponse,
+        Tasks::Calibrate,
+        Tasks::Evaluate,
+        Tasks::FitOneStandardWithPlot,
+        Tasks::Quantify,
+        Tasks::BulkSumAndMaxValue,
+        Tasks::Map,
+        Tasks::ConvertSddData
+    ];
+
+    /// A form with every field filled in with its own name, run from the crate directory
+    fn filled_form(task: Tasks) -> Form {
+        Form {
+            task,
+            working_dir: env!("CARGO_MANIFEST_DIR").to_string(),
+            config_file: String::from("config"),
+            calib_file: String::from("calib"),
+            standards_file: String::from("stds"),
+            spectrum_file: String::from("spectrum"),
+            map_file: String::from("map"),
+            plot_file: String::from("plot"),
+            log_file: String::from("log"),
+            element_controls: String::from("Fe,Ca"),
+            sdd_file: String::from("sdd"),
+            edr_file: String::from("edr"),
+            cli_args: String::new(),
+            background: background::Background::default(),
+            detector: detector::Detector::default(),
+            shelf: background::ParamValues::default(),
+            map_outputs: map_outputs::MapOutputs::default(),
+            fit: fit_options::FitOptions::default(),
+            standard: String::new(),
+            energy: energy_calibration::EnergyCalibration::default(),
+            eval_file: String::new(),
+            min_weight: String::new()
+        }
+    }
+
+    /// A form whose inputs all exist and whose outputs all go in the crate directory
+    fn valid_form(task: Tasks) -> Form {
+        let mut form = filled_form(task);
+        for field in Field::PATHS {
+            let spec = task_spec(task).and_then(|s| s.field(field));
+            *form.value_mut(field) = match spec.map(|s| s.direction) {
+                Some(Direction::Input) => String::from("Cargo.toml"),
+                _ => String::from("output.csv")
+            };
+        }
+        form
+    }
+
+    fn filled_args(task: Tasks) -> Vec<String> {
+        build_args(&filled_form(task), &[])
+    }
+
+    #[test]
+    fn energy_calibration() {
+        assert_eq!(filled_args(Tasks::EnergyCalibration), ["ene", "spectrum", "Fe,Ca", "log"]);
+    }
+
+    #[test]
+    fn plot_spectrum() {
+        assert_eq!(filled_args(Tasks::PlotSpectrum), ["plo", "spectrum", "plot", "log"]);
+    }
+
+    #[test]
+    fn calculate_primary_spectrum() {
+        assert_eq!(filled_args(Tasks::CalculatePrimarySpectrum), ["pri", "config", "plot", "log"]);
+    }
+
+    #[test]
+    fn calculate_full_spectrum() {
+        assert_eq!(filled_args(Tasks::CalculateFullSpectrum), ["calc", "config", "stds", "plot", "log"]);
+    }
+
+    #[test]
+    fn compare_measured_calculated() {
+        assert_eq!(filled_args(Tasks::CompareMeasuredCalculated), ["com", "config", "stds", "spectrum", "plot", "log"]);
+    }
+
+    #[test]
+    fn optic_response() {
+        assert_eq!(filled_args(Tasks::OpticResponse), ["opt", "config", "stds", "spectrum", "Fe,Ca", "plot", "log"]);
+        // The element list can be empty but must still be passed
+        let mut form = filled_form(Tasks::OpticResponse);
+        form.element_controls.clear();
+        form.log_file.clear();
+        assert_eq!(build_args(&form, &[]), ["opt", "config", "stds", "spectrum", "", "plot"]);
+    }
+
+    #[test]
+    fn calibrate() {
+        assert_eq!(filled_args(Tasks::Calibrate), ["cal", "config", "stds", "calib", "Fe,Ca", "log"]);
+        let mut form = filled_form(Tasks::Calibrate);
+        form.element_controls.clear();
+        form.log_file.clear();
+        assert_eq!(build_args(&form, &[]), ["cal", "config", "stds", "calib", ""]);
+        assert_eq!(task_spec(Tasks::Calibrate).unwrap().field(Field::Calibration).unwrap().direction, Direction::Output);
+    }
+
+    #[test]
+    fn evaluate() {
+        assert_eq!(filled_args(Tasks::Evaluate), ["eva", "config", "stds", "calib", "Fe,Ca", "map", "log"]);
+    }
+
+    #[test]
+    fn fit_one_standard() {
+        assert_eq!(filled_args(Tasks::FitOneStandardWithPlot), ["fits", "config", "stds", "Fe,Ca", "plot", "log"]);
+    }
+
+    #[test]
+    fn quantify() {
+        assert_eq!(filled_args(Tasks::Quantify), ["qua", "config", "calib", "spectrum", "Fe,Ca", "plot", "log"]);
+        // The plot file is optional, but a placeholder is needed before a log file or options
+        let mut form = filled_form(Tasks::Quantify);
+        form.plot_file.clear();
+        assert_eq!(build_args(&form, &[]), ["qua", "config", "calib", "spectrum", "Fe,Ca", "", "log"]);
+        form.log_file.clear();
+        assert_eq!(build_args(&form, &[]), ["qua", "config", "calib", "spectrum", "Fe,Ca"]);
+        assert_eq!(build_args(&form, &[String::from("-t,4")]), ["qua", "config", "calib", "spectrum", "Fe,Ca", "", "", "-t,4"]);
+    }
+
+    #[test]
+    fn bulk_sum_and_max_value() {
+        assert_eq!(filled_args(Tasks::BulkSumAndMaxValue), ["sum", "config", "calib", "spectrum", "Fe,Ca", "plot", "log"]);
+    }
+
+    #[test]
+    fn map() {
+        assert_eq!(filled_args(Tasks::Map), ["map", "config", "calib", "spectrum", "Fe,Ca", "map", "log"]);
+    }
+
+    #[test]
+    fn convert_sdd_data() {
+        assert_eq!(filled_args(Tasks::ConvertSddData), ["ems", "sdd", "edr", "log"]);
+    }
+
+    #[test]
+    fn no_task() {
+        assert!(task_spec(Tasks::None).is_none());
+        assert!(filled_args(Tasks::None).is_empty());
+    }
+
+    #[test]
+    fn background_options() {
+        let mut form = valid_form(Tasks::Map);
+        form.background.low = background::ParamValues { enabled: true, values: vec![String::from("-1"), String::from("-5")] };
+        form.background.high = background::ParamValues { enabled: true, values: vec![String::from("0"), String::new(), String::from("60"), String::new()] };
+        form.background.crossover = background::ParamValues { enabled: false, values: vec![String::from("7000")] };
+        assert_eq!(options(&form), ["-b,-1,-5", "-bh,0,10,60"]);
+        assert!(option_errors(&form).is_empty());
+        assert!(task_spec(Tasks::Map).unwrap().ready(&form));
+
+        // Out of range and non-numeric entries are reported and block the run
+        form.background.high.values[2] = String::from("-3");
+        form.background.high.values[3] = String::from("abc");
+        assert_eq!(option_errors(&form).len(), 2);
+        assert!(!task_spec(Tasks::Map).unwrap().ready(&form));
+
+        // Tasks that don't read spectra ignore the background
+        form.task = Tasks::CalculatePrimarySpectrum;
+        assert!(options(&form).is_empty());
+        assert!(option_errors(&form).is_empty());
+    }
+
+    #[test]
+    fn detector_options() {
+        let mut form = filled_form(Tasks::Quantify);
+        form.detector = detector::Detector::B;
+        form.shelf = background::ParamValues { enabled: true, values: vec![String::from("1.2"), String::new(), String::from("0.5")] };
+        assert_eq!(options(&form), ["-d,1", "-T,1.2,0,0.5"]);
+        assert_eq!(runs(&form).len(), 1);
+
+        // -T only for tasks that read a configuration file, -d only for those that read spectra
+        form.task = Tasks::PlotSpectrum;
+        assert_eq!(options(&form), ["-d,1"]);
+        form.task = Tasks::CalculatePrimarySpectrum;
+        assert_eq!(options(&form), ["-T,1.2,0,0.5"]);
+
+        form.shelf.values[2] = String::from("1.5");
+        assert_eq!(option_errors(&form).len(), 1);
+    }
+
+    #[test]
+    fn detectors_run_separately() {
+        let mut form = filled_form(Tasks::Evaluate);
+        form.map_file = String::from("out/map.csv");
+        form.detector = detector::Detector::Separately;
+        let split = runs(&form);
+        assert_eq!(split.len(), 2);
+        assert_eq!(args(&split[0]), ["eva", "config", "stds", "calib", "Fe,Ca", "out/map_A.csv", "log", "-d,0"]);
+        assert_eq!(args(&split[1]), ["eva", "config", "stds", "calib", "Fe,Ca", "out/map_B.csv", "log", "-d,1"]);
+
+        // Tasks without detector selection run once
+        form.task = Tasks::CalculateFullSpectrum;
+        assert_eq!(runs(&form).len(), 1);
+    }
+
+    #[test]
+    fn map_columns() {
+        let mut form = filled_form(Tasks::Map);
+        form.map_outputs.enabled = true;
+        assert_eq!(option_errors(&form).len(), 1);
+        form.map_outputs.set('F', true);
+        form.map_outputs.set('P', true);
+        form.map_outputs.set('E', true);
+        form.map_outputs.set('P', false);
+        assert_eq!(options(&form), ["-q,FE"]);
+        assert!(option_errors(&form).is_empty());
+
+        // Z labels the element columns by atomic number
+        form.map_outputs.set('Z', true);
+        assert_eq!(options(&form), ["-q,FEZ"]);
+        assert!(option_errors(&form).is_empty());
+        form.map_outputs.letters = String::from("Z");
+        assert_eq!(option_errors(&form), ["-q: select at least one map column"]);
+
+        form.map_outputs.letters = String::from("FEw");
+        assert_eq!(option_errors(&form), ["-q: 'w' is not a map column PIQUANT knows"]);
+
+        // Only tasks that write map files take -q
+        form.task = Tasks::Quantify;
+        assert!(options(&form).is_empty());
+        assert!(option_errors(&form).is_empty());
+    }
+
+    #[test]
+    fn fit_options() {
+        let mut form = filled_form(Tasks::Quantify);
+        form.fit.freeze_energy = true;
+        form.fit.convolve_compton = true;
+        form.fit.normalize = true;
+        form.fit.normalization = String::from("100");
+        form.fit.iron_ratio_enabled = true;
+        form.fit.iron_ratio = String::from("1.5");
+        form.fit.carbonates = true;
+        assert_eq!(options(&form), ["-f", "-v", "-c", "-n,100", "-Fe,1.5"]);
+        assert!(option_errors(&form).is_empty());
+
+        // Bad numbers are reported
+        form.fit.normalization = String::from("99.5");
+        form.fit.iron_ratio = String::from("-1");
+        assert_eq!(option_errors(&form).len(), 2);
+
+        // Only the tasks that quantify normalize; -f, -g and -v go with any fit
+        form.task = Tasks::PlotSpectrum;
+        assert_eq!(options(&form), ["-f", "-v"]);
+        assert!(option_errors(&form).is_empty());
+    }
+
+    #[test]
+    fn carbonate_elements() {
+        let mut form = filled_form(Tasks::Map);
+        form.element_controls = String::from("Ca_K,Si,Fe_K");
+        form.fit.carbonates = true;
+        form.fit.carbonate_elements = String::from("Fe, Ca");
+        assert_eq!(args(&form), ["map", "config", "calib", "spectrum", "Si,CO3,Ca_K,Fe_K", "map", "log"]);
+        assert!(option_errors(&form).is_empty());
+
+        // Only elements that form carbonates, and are in the element list, can be chosen
+        form.fit.carbonate_elements = String::from("Si,Mg");
+        assert_eq!(option_errors(&form).len(), 2);
+
+        // Tasks that don't quantify leave the element list alone
+        form.task = Tasks::EnergyCalibration;
+        assert_eq!(args(&form), ["ene", "spectrum", "Ca_K,Si,Fe_K", "log"]);
+        assert!(option_errors(&form).is_empty());
+    }
+
+    #[test]
+    fn standard_selection() {
+        let mut form = filled_form(Tasks::Evaluate);
+        assert!(options(&form).is_empty());
+        form.standard = String::from("BHVO-2");
+        assert_eq!(options(&form), ["-s,BHVO-2"]);
+
+        // Calibrate always goes through every standard
+        form.task = Tasks::Calibrate;
+        assert!(options(&form).is_empty());
+    }
+
+    #[test]
+    fn energy_calibration_override() {
+        let output = "Energy calibration   eV start = -17.3  eV/ch = 7.9766                                      (-e,-17.3,7.9766)\n";
+        let (start, per_channel) = energy_calibration::from_output(output).unwrap();
+        let mut form = filled_form(Tasks::Quantify);
+        form.energy = energy_calibration::EnergyCalibration { enabled: true, start, per_channel };
+        assert_eq!(options(&form), ["-e,-17.3,7.9766"]);
+        assert!(option_errors(&form).is_empty());
+
+        form.energy.per_channel = String::from("0");
+        assert_eq!(option_errors(&form).len(), 1);
+
+        // Energy Calibration works the calibration out rather than taking one
+        form.task = Tasks::EnergyCalibration;
+        assert!(options(&form).is_empty());
+        assert!(energy_calibration::from_output("Energy calibration failed").is_none());
+    }
+
+    #[test]
+    fn evaluation_file_and_weight() {
+        let mut form = valid_form(Tasks::Calibrate);
+        form.eval_file = String::from("evaluation.csv");
+        form.min_weight = String::from("0.5");
+        assert_eq!(options(&form), ["-u,evaluation.csv", "-w,0.5"]);
+        assert!(task_spec(Tasks::Calibrate).unwrap().ready(&form));
+
+        form.eval_file = String::from("no_such_dir/evaluation.csv");
+        form.min_weight = String::from("heavy");
+        assert_eq!(option_errors(&form).len(), 2);
+
+        // Only Calibrate and Evaluate take them
+        form.task = Tasks::Map;
+        assert!(options(&form).is_empty());
+        assert!(option_errors(&form).is_empty());
+    }
+
+    #[test]
+    fn cli_arguments() {
+        let mut form = filled_form(Tasks::Quantify);
+        form.cli_args = String::from("-t,4  -m '-u,out dir/eval.csv' \"C:\\data\\x\" \"\"");
+        assert_eq!(&args(&form)[7..], ["-t,4", "-m", "-u,out dir/eval.csv", "C:\\data\\x", ""]);
+        assert!(cli_clashes(&form).is_empty());
+
+        // Flags already set in the form are named
+        form.detector = detector::Detector::Separately;
+        form.fit.freeze_energy = true;
+        form.cli_args = String::from("-d,0 -f -f -t,4");
+        assert_eq!(cli_clashes(&form), ["-d", "-f"]);
+
+        form.cli_args = String::from("-s,\"BHVO");
+        assert_eq!(option_errors(&form).len(), 1);
+    }
+
+    #[test]
+    fn existing_outputs_are_found() {
+        let mut form = valid_form(Tasks::Quantify);
+        assert!(existing_outputs(&form).is_empty());
+        form.plot_file = String::from("Cargo.toml");
+        form.log_file = String::from("Cargo.toml");
+        assert_eq!(existing_outputs(&form), [Path::new(env!("CARGO_MANIFEST_DIR")).join("Cargo.toml")]);
+    }
+
+    #[test]
+    fn log_output_is_followed() {
+        let mut form = valid_form(Tasks::EnergyCalibration);
+        form.log_file.clear();
+        assert_eq!(log_output(&form), None);
+        form.working_dir = String::from("/data");
+        form.log_file = String::from("run.log");
+        assert_eq!(log_output(&form), Some(PathBuf::from("/data/run.log")));
+        // PIQUANT adds to the log, so it's never a file to be replaced
+        assert_eq!(task_spec(form.task).unwrap().field(Field::Log).unwrap().direction, Direction::Append);
+        assert_eq!(build_args(&form, &[]).last().map(String::as_str), Some("run.log"));
+    }
+
+    #[test]
+    fn plot_outputs() {
+        let mut form = valid_form(Tasks::PlotSpectrum);
+        form.working_dir = String::from("/data");
+        form.plot_file = String::from("plot.CSV");
+        assert_eq!(plot_output(&form), Some(PathBuf::from("/data/plot.CSV")));
+        // Anything but an EMSA spectrum is written as a CSV plot
+        form.plot_file = String::from("plot.txt");
+        assert_eq!(plot_output(&form), Some(PathBuf::from("/data/plot.txt")));
+        form.plot_file = String::from("plot");
+        assert_eq!(plot_output(&form), Some(PathBuf::from("/data/plot")));
+        form.plot_file = String::from("plot.MSA");
+        assert_eq!(plot_output(&form), None);
+        form.plot_file = String::from("plot.csv");
+        form.task = Tasks::EnergyCalibration;
+        assert_eq!(plot_output(&form), None);
+
+        // Evaluate's -u file is a plot only for a single standard
+        form.task = Tasks::Evaluate;
+        form.eval_file = String::from("eval.csv");
+        assert_eq!(plot_output(&form), None);
+        form.standard = String::from("BHVO-2");
+        assert_eq!(plot_output(&form), Some(PathBuf::from("/data/eval.csv")));
+        form.task = Tasks::Calibrate;
+        assert_eq!(plot_output(&form), None);
+    }
+
+    #[cfg(unix)]
+    #[test]
+    fn outputs_need_a_writable_directory() {
+        use std::os::unix::fs::PermissionsExt;
+        let dir = std::env::temp_dir().join(format!("piquant-gui-readonly-{}", std::process::id()));
+        fs::create_dir_all(&dir).unwrap();
+        let output = dir.join("map.csv").display().to_string();
+        assert!(output_dir_writable(&output, ""));
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o555)).unwrap();
+        let writable = output_dir_writable(&output, "");
+        fs::set_permissions(&dir, fs::Permissions::from_mode(0o755)).unwrap();
+        fs::remove_dir(&dir).unwrap();
+        assert!(!writable);
+    }
+
+    #[test]
+    fn log_file_is_last_and_optional() {
+        for task in ALL_TASKS {
+            let spec = task_spec(task).unwrap();
+            assert_eq!(spec.fields.last(), Some(&LOG), "{:?}", task);
+            let mut form = filled_form(task);
+            form.log_file.clear();
+            assert_ne!(build_args(&form, &[]).last().map(String::as_str), Some(""), "{:?}", task);
+        }
+    }
+
+    #[test]
+    fn every_task_is_ready_with_valid_fields() {
+        for task in ALL_TASKS {
+            assert!(task_spec(task).unwrap().ready(&valid_form(task)), "{:?}", task);
+        }
+    }
+
+    #[test]
+    fn required_fields_must_be_filled_in() {
+        for task in ALL_TASKS {
+            let spec = task_spec(task).unwrap();
+            for field in spec.fields {
+                let mut form = valid_form(task);
+                form.value_mut(field.field).clear();
+                assert_eq!(spec.ready(&form), field.need != Need::Required, "{:?} {:?}", task, field.field);
+            }
+        }
+    }
+
+    #[test]
+    fn inputs_must_exist() {
+        for task in ALL_TASKS {
+            let spec = task_spec(task).unwrap();
+            for field in spec.fields.iter().filter(|f| f.direction == Direction::Input && f.field != Field::ElementControls) {
+                let mut form = valid_form(task);
+                *form.value_mut(field.field) = String::from("missing.file");
+                assert!(!spec.ready(&form), "{:?} {:?}", task, field.field);
+            }
+        }
+    }
+
+    #[test]
+    fn outputs_need_an_existing_directory() {
+        for task in ALL_TASKS {
+            let spec = task_spec(task).unwrap();
+            for field in spec.fields.iter().filter(|f| f.direction != Direction::Input) {
+                let mut form = valid_form(task);
+                *form.value_mut(field.field) = String::from("no_such_dir/output.csv");
+                assert!(!spec.ready(&form), "{:?} {:?}", task, field.field);
+            }
+        }
+    }
+}
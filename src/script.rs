@@ -0,0 +1,182 @@
+// Description: PIQUANT command lines quoted for the platform's shell, and scripts that repeat a set of runs.
+
+use std::env;
+use std::path::Path;
+
+use crate::functions;
+
+/// The command language a command line or script is written for
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum Shell {
+    // sh and compatible shells
+    Posix,
+    // cmd.exe batch files
+    Batch
+}
+
+impl Shell {
+    /// The shell for the platform the GUI runs on
+    pub fn native() -> Shell {
+        if cfg!(target_os = "windows") {
+            Shell::Batch
+        } else {
+            Shell::Posix
+        }
+    }
+
+    /// File types offered when saving a script
+    pub fn filters(&self) -> &'static [functions::FileFilter] {
+        match self {
+            Shell::Posix => &[functions::FileFilter { name: "Shell script", extensions: &["sh"] }],
+            Shell::Batch => &[functions::FileFilter { name: "Batch file", extensions: &["bat", "cmd"] }]
+        }
+    }
+
+    /// File name offered when saving a script
+    pub fn file_name(&self) -> &'static str {
+        match self {
+            Shell::Posix => "piquant_runs.sh",
+            Shell::Batch => "piquant_runs.bat"
+        }
+    }
+}
+
+/// Quote an argument so the shell passes it to PIQUANT unchanged
+pub fn quote(arg: &str, shell: Shell) -> String {
+    match shell {
+        Shell::Posix => {
+            let safe = |c: char| c.is_ascii_alphanumeric() || "-_+=.,/:@%".contains(c);
+            if !arg.is_empty() && arg.chars().all(safe) {
+                arg.to_string()
+            } else {
+                format!("'{}'", arg.replace('\'', "'\\''"))
+            }
+        }
+        Shell::Batch => {
+            if !arg.is_empty() && !arg.contains([' ', '\t', '"', '&', '|', '<', '>', '^', '(', ')']) {
+                return arg.to_string();
+            }
+            // Rules the C runtime uses to split a Windows command line: backslashes are only
+            // special before a quote, so those (and any before the closing quote) are doubled
+            let mut quoted = String::from("\"");
+            let mut backslashes = 0;
+            for c in arg.chars() {
+                match c {
+                    '\\' => backslashes += 1,
+                    '"' => {
+                        quoted.push_str(&"\\".repeat(backslashes * 2 + 1));
+                        backslashes = 0;
+                    }
+                    _ => {
+                        quoted.push_str(&"\\".repeat(backslashes));
+                        backslashes = 0;
+                    }
+                }
+                if c != '\\' {
+                    quoted.push(c);
+                }
+            }
+            quoted.push_str(&"\\".repeat(backslashes * 2));
+            quoted.push('"');
+            quoted
+        }
+    }
+}
+
+/// The full command line for a run
+pub fn command_line(program: &str, args: &[String], shell: Shell) -> String {
+    let mut line = quote(program, shell);
+    for arg in args {
+        line.push(' ');
+        line.push_str(&quote(arg, shell));
+    }
+    line
+}
+
+/// A script repeating runs in order, each from its working directory, stopping at the first that fails
+pub fn script(program: &str, runs: &[(&str, &[String])], shell: Shell) -> String {
+    let mut lines = Vec::new();
+    match shell {
+        Shell::Posix => {
+            lines.push(String::from("#!/bin/sh"));
+            lines.push(format!("# PIQUANT runs exported by {} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")));
+            lines.push(String::from("set -e"));
+        }
+        Shell::Batch => {
+            lines.push(String::from("@echo off"));
+            lines.push(format!("rem PIQUANT runs exported by {} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION")));
+        }
+    }
+    for (working_dir, args) in runs {
+        // Relative paths in the arguments are taken from the run's working directory
+        let dir = functions::make_absolute(if working_dir.is_empty() { "." } else { working_dir }, "");
+        lines.push(String::new());
+        match shell {
+            Shell::Posix => {
+                lines.push(format!("cd {}", quote(&dir, shell)));
+                lines.push(command_line(program, args, shell));
+            }
+            Shell::Batch => {
+                // Percent signs would be expanded as variables in a batch file, though not when typed at the prompt
+                lines.push(format!("cd /d {}", quote(&dir, shell)).replace('%', "%%"));
+                lines.push(command_line(program, args, shell).replace('%', "%%"));
+                lines.push(String::from("if errorlevel 1 exit /b %errorlevel%"));
+            }
+        }
+    }
+    let newline = match shell {
+        Shell::Posix => "\n",
+        Shell::Batch => "\r\n"
+    };
+    lines.join(newline) + newline
+}
+
+/// Write a script, making it executable where that applies
+pub fn write(path: &Path, text: &str) -> std::io::Result<()> {
+    std::fs::write(path, text)?;
+    #[cfg(unix)]
+    {
+        use std::os::unix::fs::PermissionsExt;
+        std::fs::set_permissions(path, std::fs::Permissions::from_mode(0o755))?;
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn posix_quoting() {
+        let args = [String::from("qua"), String::from("my config.msa"), String::new(), String::from("-b,-1,-5"), String::from("it's")];
+        assert_eq!(command_line("/opt/PIQUANT", &args, Shell::Posix), "/opt/PIQUANT qua 'my config.msa' '' -b,-1,-5 'it'\\''s'");
+    }
+
+    #[test]
+    fn batch_quoting() {
+        assert_eq!(quote("C:\\data\\map.csv", Shell::Batch), "C:\\data\\map.csv");
+        assert_eq!(quote("C:\\my data\\", Shell::Batch), "\"C:\\my data\\\\\"");
+        assert_eq!(quote("say \"hi\"", Shell::Batch), "\"say \\\"hi\\\"\"");
+        assert_eq!(quote("", Shell::Batch), "\"\"");
+    }
+
+    #[test]
+    fn batch_percent_signs() {
+        // Typed or pasted at the prompt, a percent sign goes through as it is
+        let args = [String::from("qua"), String::from("50%.csv")];
+        assert_eq!(command_line("PIQUANT.exe", &args, Shell::Batch), "PIQUANT.exe qua 50%.csv");
+        // In a batch file it has to be doubled, except where a variable is meant
+        let text = script("PIQUANT.exe", &[("C:\\100% data", &args)], Shell::Batch);
+        assert!(text.contains("100%% data\"\r\nPIQUANT.exe qua 50%%.csv\r\nif errorlevel 1 exit /b %errorlevel%"));
+    }
+
+    #[test]
+    fn scripts() {
+        let args = [String::from("ene"), String::from("a.msa"), String::from("Fe,Ca")];
+        let text = script("PIQUANT", &[("/data", &args), ("/data/b", &args)], Shell::Posix);
+        assert!(text.starts_with("#!/bin/sh\n"));
+        assert!(text.ends_with("cd /data/b\nPIQUANT ene a.msa Fe,Ca\n"));
+        let text = script("PIQUANT.exe", &[("C:\\data", &args)], Shell::Batch);
+        assert!(text.contains("PIQUANT.exe ene a.msa Fe,Ca\r\nif errorlevel 1"));
+    }
+}
@@ -0,0 +1,48 @@
+// Description: user settings that persist between sessions.
+
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+
+use crate::map_outputs;
+
+// Key the settings are stored under in eframe's storage
+const SETTINGS_KEY: &str = "piquant_settings";
+
+#[derive(Debug, Default, Serialize, Deserialize)]
+#[serde(default)]
+pub struct Settings {
+    // Path to the PIQUANT executable; empty to fall back to PATH or the bundled binary
+    pub piquant_path: String,
+    // Map column selections saved by the user
+    pub map_presets: Vec<map_outputs::Preset>,
+    // Directory each file dialog was last used in, keyed by what it picks, e.g. "spectrum file"
+    pub last_dirs: BTreeMap<String, String>
+}
+
+impl Settings {
+    /// Directory to open a file dialog in, if one has been used for this kind of file before
+    pub fn last_dir(&self, key: &str) -> Option<PathBuf> {
+        self.last_dirs.get(key).map(PathBuf::from)
+    }
+
+    /// Remember where a file was picked, for the next dialog of the same kind
+    pub fn remember_dir(&mut self, key: &str, picked: &Path) {
+        let dir = if picked.is_dir() { Some(picked) } else { picked.parent() };
+        if let Some(dir) = dir.filter(|d| !d.as_os_str().is_empty()) {
+            self.last_dirs.insert(key.to_string(), dir.display().to_string());
+        }
+    }
+}
+
+/// Load settings saved by a previous session, or defaults if there are none
+pub fn load(storage: Option<&dyn eframe::Storage>) -> Settings {
+    storage
+        .and_then(|s| eframe::get_value(s, SETTINGS_KEY))
+        .unwrap_or_default()
+}
+
+/// Save settings for the next session
+pub fn save(storage: &mut dyn eframe::Storage, settings: &Settings) {
+    eframe::set_value(storage, SETTINGS_KEY, settings);
+}
@@ -0,0 +1,198 @@
+// Description: reads the standards in a standards input file, so one can be picked for the -s option.
+// Follows setupStandardsCSV.cpp and setupStandardsTXT.cpp closely enough to number the standards as PIQUANT does.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::SystemTime;
+
+/// A standard as PIQUANT numbers it; -s takes the index or any of the names
+#[derive(Debug, Clone, PartialEq)]
+pub struct Standard {
+    pub index: usize,
+    pub names: Vec<String>
+}
+
+impl Standard {
+    /// Text shown in the standard picker
+    pub fn label(&self) -> String {
+        match self.names.split_first() {
+            Some((name, [])) => format!("#{} {}", self.index, name),
+            Some((name, others)) => format!("#{} {} ({})", self.index, name, others.join(", ")),
+            None => format!("#{}", self.index)
+        }
+    }
+}
+
+/// The -s value for a standard: its first name when that picks it out, otherwise its number
+pub fn selection(standard: &Standard, standards: &[Standard]) -> String {
+    let Some(name) = standard.names.first() else {
+        return standard.index.to_string();
+    };
+    // PIQUANT takes anything starting with a number as an index, and stops at the first standard with the name
+    let numeric = name.trim_start().starts_with(|c: char| c.is_ascii_digit() || c == '-' || c == '+');
+    let first = standards.iter().find(|s| s.names.contains(name)).map(|s| s.index);
+    if numeric || name.contains(',') || first != Some(standard.index) {
+        standard.index.to_string()
+    } else {
+        name.clone()
+    }
+}
+
+/// Find the standard a -s value selects
+pub fn find<'a>(value: &str, standards: &'a [Standard]) -> Option<&'a Standard> {
+    match value.trim().parse::<usize>() {
+        Ok(index) => standards.get(index),
+        Err(_) => standards.iter().find(|s| s.names.iter().any(|n| n == value))
+    }
+}
+
+/// Standards read from a file, kept until the file or its path changes
+#[derive(Debug)]
+pub struct StandardsList {
+    path: PathBuf,
+    modified: Option<SystemTime>,
+    pub standards: Result<Vec<Standard>, String>
+}
+
+impl StandardsList {
+    /// Read the file, or reuse the last reading if it hasn't changed
+    pub fn load(list: &mut Option<StandardsList>, path: &Path) {
+        let modified = fs::metadata(path).and_then(|m| m.modified()).ok();
+        if list.as_ref().is_some_and(|l| l.path == path && l.modified == modified) {
+            return;
+        }
+        *list = Some(StandardsList { path: path.to_path_buf(), modified, standards: read(path) });
+    }
+}
+
+/// Read the standards in a .csv or .txt standards input file
+pub fn read(path: &Path) -> Result<Vec<Standard>, String> {
+    let extension = path.extension().and_then(|e| e.to_str()).unwrap_or("").to_ascii_lowercase();
+    if extension != "csv" && extension != "txt" {
+        return Err(String::from("standards input files can only be .txt or .csv"));
+    }
+    let text = fs::read_to_string(path).map_err(|e| format!("could not read {}: {}", path.display(), e))?;
+    Ok(if extension == "csv" { parse_csv(&text) } else { parse_txt(&text) })
+}
+
+/// Standards in a CSV file: each STANDARD keyword names one, and each SPECTRUM keyword after it adds an entry
+pub fn parse_csv(text: &str) -> Vec<Standard> {
+    let mut standards = Vec::new();
+    let mut names: Vec<String> = Vec::new();
+    let mut has_elements = false;
+    let mut between_standards = true;
+    let mut add = |names: &Vec<String>, has_elements: bool| {
+        // PIQUANT drops standards without any elements
+        if has_elements {
+            let index = standards.len();
+            standards.push(Standard { index, names: names.clone() });
+        }
+    };
+    for line in text.lines() {
+        let records: Vec<&str> = line.split(',').map(str::trim).collect();
+        if records.iter().all(|r| r.is_empty()) || (between_standards && records[0].is_empty()) {
+            continue;
+        }
+        match records[0].to_ascii_uppercase().as_str() {
+            "STANDARD" => {
+                if !between_standards {
+                    add(&names, has_elements);
+                }
+                names = records[1..].iter().filter(|r| !r.is_empty()).map(|r| r.to_string()).collect();
+                has_elements = false;
+                between_standards = false;
+            }
+            "SPECTRUM" => {
+                if records.get(1).is_some_and(|r| !r.is_empty()) {
+                    add(&names, has_elements);
+                    between_standards = true;
+                }
+            }
+            "PIQUANT" | "ELEMENT" | "CARBONATES" | "FRACTIONS" | "THICKNESS" | "DENSITY" | "COMMENT" => {}
+            _ => has_elements = true
+        }
+    }
+    if !between_standards {
+        add(&names, has_elements);
+    }
+    standards
+}
+
+/// Standards in a TXT file: a spectrum file name, then the element count and pairs of element and percent.
+/// TXT files have no names, so PIQUANT uses the spectrum file name
+pub fn parse_txt(text: &str) -> Vec<Standard> {
+    let mut standards = Vec::new();
+    let mut lines = text.lines().map(str::trim).filter(|l| !l.is_empty() && !l.starts_with("//"));
+    // The element list comes first: a count, then the elements on the next line
+    if lines.next().is_none() || lines.next().is_none() {
+        return standards;
+    }
+    while let Some(spectrum) = lines.next() {
+        let mut tokens: Vec<&str> = Vec::new();
+        let mut count = None;
+        for line in lines.by_ref() {
+            tokens.extend(line.split_whitespace());
+            count = tokens.first().and_then(|t| t.parse::<i64>().ok());
+            if count.is_none_or(|n| tokens.len() as i64 > 2 * n) {
+                break;
+            }
+        }
+        if count.is_some_and(|n| n > 0) {
+            let name = spectrum.rsplit(['/', '\\']).next().unwrap_or(spectrum);
+            standards.push(Standard { index: standards.len(), names: vec![name.to_string()] });
+        }
+    }
+    standards
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn csv_standards() {
+        let text = "\
+COMMENT, calibration targets
+STANDARD, BHVO-2, Basalt
+Si, K, , , 23.3%
+SPECTRUM, bhvo.msa
+SPECTRUM, bhvo_2.msa
+,,,,
+STANDARD, Empty
+STANDARD, 1-Teflon
+C, K, , , 24%
+";
+        let standards = parse_csv(text);
+        let names: Vec<&str> = standards.iter().map(|s| s.names[0].as_str()).collect();
+        assert_eq!(names, ["BHVO-2", "BHVO-2", "1-Teflon"]);
+        assert_eq!(standards[0].label(), "#0 BHVO-2 (Basalt)");
+
+        // Names that PIQUANT would read as a number, or that pick an earlier standard, are passed by index
+        assert_eq!(selection(&standards[0], &standards), "BHVO-2");
+        assert_eq!(selection(&standards[1], &standards), "1");
+        assert_eq!(selection(&standards[2], &standards), "2");
+        assert_eq!(find("Basalt", &standards), Some(&standards[0]));
+        assert_eq!(find("2", &standards), Some(&standards[2]));
+        assert_eq!(find("Granite", &standards), None);
+    }
+
+    #[test]
+    fn txt_standards() {
+        let text = "\
+// element list
+3
+Si Fe Ca
+data/bhvo.msa
+2 Si 23.3 Fe
+8.6
+empty.msa
+0
+glass.msa
+1 Si 30
+";
+        let standards = parse_txt(text);
+        let names: Vec<&str> = standards.iter().map(|s| s.names[0].as_str()).collect();
+        assert_eq!(names, ["bhvo.msa", "glass.msa"]);
+        assert_eq!(standards[1].index, 1);
+    }
+}
@@ -0,0 +1,2043 @@
+// Description: X-ray emission lines of the elements, for marking peaks on spectrum plots.
+// The lines are those PIQUANT fits: XrayLines.cpp built for each K, L and M vacancy, with edge energies from XrayEdge.cpp.
+
+use crate::fit_options;
+
+/// An emission line; its intensity is relative to the other lines filling the same vacancy
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct Line {
+    pub z: u8,
+    // Edge with the vacancy the line fills, e.g. "K" or "L3"
+    pub vacancy: &'static str,
+    pub iupac: &'static str,
+    pub siegbahn: &'static str,
+    // eV
+    pub energy: f32,
+    pub relative: f32
+}
+
+const fn line(z: u8, vacancy: &'static str, iupac: &'static str, siegbahn: &'static str, energy: f32, relative: f32) -> Line {
+    Line { z, vacancy, iupac, siegbahn, energy, relative }
+}
+
+// Element symbols by atomic number, as in Element.cpp
+pub const SYMBOLS: [&str; 100] = [
+    "H", "He", "Li", "Be", "B", "C", "N", "O", "F", "Ne",
+    "Na", "Mg", "Al", "Si", "P", "S", "Cl", "Ar", "K", "Ca",
+    "Sc", "Ti", "V", "Cr", "Mn", "Fe", "Co", "Ni", "Cu", "Zn",
+    "Ga", "Ge", "As", "Se", "Br", "Kr", "Rb", "Sr", "Y", "Zr",
+    "Nb", "Mo", "Tc", "Ru", "Rh", "Pd", "Ag", "Cd", "In", "Sn",
+    "Sb", "Te", "I", "Xe", "Cs", "Ba", "La", "Ce", "Pr", "Nd",
+    "Pm", "Sm", "Eu", "Gd", "Tb", "Dy", "Ho", "Er", "Tm", "Yb",
+    "Lu", "Hf", "Ta", "W", "Re", "Os", "Ir", "Pt", "Au", "Hg",
+    "Tl", "Pb", "Bi", "Po", "At", "Rn", "Fr", "Ra", "Ac", "Th",
+    "Pa", "U", "Np", "Pu", "Am", "Cm", "Bk", "Cf", "Es", "Fm"
+];
+
+/// Symbol of an element, by atomic number
+pub fn symbol(z: u8) -> &'static str {
+    SYMBOLS.get((z as usize).wrapping_sub(1)).copied().unwrap_or("")
+}
+
+/// Atomic number of an element symbol
+pub fn atomic_number(symbol: &str) -> Option<u8> {
+    SYMBOLS.iter().position(|s| *s == symbol).map(|i| i as u8 + 1)
+}
+
+/// Elements named in an element list, such as the element fit controls
+pub fn elements_in(element_list: &str) -> Vec<u8> {
+    let mut elements: Vec<u8> = Vec::new();
+    for z in fit_options::entries(element_list).filter_map(|e| atomic_number(fit_options::symbol(e))) {
+        if !elements.contains(&z) {
+            elements.push(z);
+        }
+    }
+    elements
+}
+
+/// The lines of an element, in order of vacancy and then energy
+pub fn lines(z: u8) -> impl Iterator<Item = &'static Line> {
+    LINES.iter().filter(move |l| l.z == z)
+}
+
+/// Row and column of an element in the periodic table; lanthanides and actinides go in rows 7 and 8
+pub fn position(z: u8) -> (usize, usize) {
+    let z = z as usize;
+    match z {
+        1 => (0, 0),
+        2 => (0, 17),
+        3..=18 => {
+            let (row, offset) = if z <= 10 { (1, z - 3) } else { (2, z - 11) };
+            (row, if offset < 2 { offset } else { offset + 10 })
+        }
+        19..=36 => (3, z - 19),
+        37..=54 => (4, z - 37),
+        57..=71 => (7, z - 57 + 2),
+        89..=103 => (8, z - 89 + 2),
+        _ => {
+            let (row, start) = if z < 87 { (5, 55) } else { (6, 87) };
+            (row, if z < start + 2 { z - start } else { z - start - 14 })
+        }
+    }
+}
+
+// Every line with any intensity, Z 3 to 100
+pub static LINES: [Line; 1926] = [
+    line(3, "K", "K-L1", "Ka3", 54.7, 1.0),
+    line(4, "K", "K-L1", "Ka3", 111.5, 1.0),
+    line(5, "K", "K-L1", "Ka3", 188.0, 0.00018225),
+    line(5, "K", "K-L2", "Ka2", 188.0, 0.999818),
+    line(6, "K", "K-L1", "Ka3", 284.2, 0.000218619),
+    line(6, "K", "K-L2", "Ka2", 284.2, 0.999781),
+    line(7, "K", "K-L1", "Ka3", 372.6, 8.5262e-05),
+    line(7, "K", "K-L2", "Ka2", 409.9, 0.334325),
+    line(7, "K", "K-L3", "Ka1", 409.9, 0.665589),
+    line(8, "K", "K-L1", "Ka3", 501.5, 9.74303e-05),
+    line(8, "K", "K-L2", "Ka2", 543.1, 0.334395),
+    line(8, "K", "K-L3", "Ka1", 543.1, 0.665507),
+    line(9, "K", "K-L1", "Ka3", 696.7, 0.000109596),
+    line(9, "K", "K-L2", "Ka2", 696.7, 0.334465),
+    line(9, "K", "K-L3", "Ka1", 696.7, 0.665425),
+    line(10, "K", "K-L1", "Ka3", 821.7, 0.000121758),
+    line(10, "K", "K-L2", "Ka2", 848.5, 0.334535),
+    line(10, "K", "K-L3", "Ka1", 848.6, 0.665344),
+    line(11, "K", "K-L1", "Ka3", 1007.3, 0.000133917),
+    line(11, "K", "K-L2", "Ka2", 1040.15, 0.334604),
+    line(11, "K", "K-L3", "Ka1", 1039.99, 0.665262),
+    line(11, "L2", "L2-M1", "Ln", 30.65, 1.0),
+    line(11, "L3", "L3-M1", "Ll", 30.81, 1.0),
+    line(12, "K", "K-L1", "Ka3", 1214.3, 0.000146073),
+    line(12, "K", "K-L2", "Ka2", 1253.22, 0.334674),
+    line(12, "K", "K-L3", "Ka1", 1253.5, 0.66518),
+    line(12, "L2", "L2-M1", "Ln", 49.78, 1.0),
+    line(12, "L3", "L3-M1", "Ll", 49.5, 1.0),
+    line(13, "K", "K-L1", "Ka3", 1441.8, 0.000156202),
+    line(13, "K", "K-L2", "Ka2", 1486.65, 0.330461),
+    line(13, "K", "K-L3", "Ka1", 1487.05, 0.656588),
+    line(13, "K", "K-M2", "Kb3", 1559.6, 0.00429714),
+    line(13, "K", "K-M3", "Kb1", 1559.6, 0.00849741),
+    line(13, "L1", "L1-M2", "Lb4", 117.8, 0.413833),
+    line(13, "L1", "L1-M3", "Lb3", 117.8, 0.586166),
+    line(13, "L2", "L2-M1", "Ln", 72.95, 1.0),
+    line(13, "L3", "L3-M1", "Ll", 72.55, 1.0),
+    line(14, "K", "K-L1", "Ka3", 1689.3, 0.000165547),
+    line(14, "K", "K-L2", "Ka2", 1739.18, 0.325473),
+    line(14, "K", "K-L3", "Ka1", 1739.58, 0.646164),
+    line(14, "K", "K-M2", "Kb3", 1839.0, 0.0094643),
+    line(14, "K", "K-M3", "Kb1", 1839.0, 0.0187338),
+    line(14, "L1", "L1-M2", "Lb4", 149.7, 0.413833),
+    line(14, "L1", "L1-M3", "Lb3", 149.7, 0.586166),
+    line(14, "L2", "L2-M1", "Ln", 99.82, 1.0),
+    line(14, "L3", "L3-M1", "Ll", 99.42, 1.0),
+    line(15, "K", "K-L1", "Ka3", 1956.5, 0.000174245),
+    line(15, "K", "K-L2", "Ka2", 2009.5, 0.320433),
+    line(15, "K", "K-L3", "Ka1", 2010.5, 0.634773),
+    line(15, "K", "K-M2", "Kb3", 2145.5, 0.014968),
+    line(15, "K", "K-M3", "Kb1", 2145.5, 0.0296514),
+    line(15, "L1", "L1-M2", "Lb4", 189.0, 0.413833),
+    line(15, "L1", "L1-M3", "Lb3", 189.0, 0.586166),
+    line(15, "L2", "L2-M1", "Ln", 136.0, 1.0),
+    line(15, "L3", "L3-M1", "Ll", 135.0, 1.0),
+    line(16, "K", "K-L1", "Ka3", 2241.1, 0.000182539),
+    line(16, "K", "K-L2", "Ka2", 2308.4, 0.315018),
+    line(16, "K", "K-L3", "Ka1", 2309.5, 0.623427),
+    line(16, "K", "K-M2", "Kb3", 2472.0, 0.0205855),
+    line(16, "K", "K-M3", "Kb1", 2472.0, 0.0407876),
+    line(16, "L1", "L1-M2", "Lb4", 230.9, 0.413833),
+    line(16, "L1", "L1-M3", "Lb3", 230.9, 0.586166),
+    line(16, "L2", "L2-M1", "Ln", 163.6, 1.0),
+    line(16, "L3", "L3-M1", "Ll", 162.5, 1.0),
+    line(17, "K", "K-L1", "Ka3", 2552.4, 0.000190286),
+    line(17, "K", "K-L2", "Ka2", 2620.4, 0.309253),
+    line(17, "K", "K-L3", "Ka1", 2622.4, 0.611655),
+    line(17, "K", "K-M2", "Kb3", 2822.4, 0.0264441),
+    line(17, "K", "K-M3", "Kb1", 2822.4, 0.052458),
+    line(17, "L1", "L1-M2", "Lb4", 270.0, 0.413833),
+    line(17, "L1", "L1-M3", "Lb3", 270.0, 0.586166),
+    line(17, "L2", "L2-M1", "Ln", 202.0, 1.0),
+    line(17, "L3", "L3-M1", "Ll", 200.0, 1.0),
+    line(18, "K", "K-L1", "Ka3", 2879.6, 0.000197461),
+    line(18, "K", "K-L2", "Ka2", 2955.3, 0.302665),
+    line(18, "K", "K-L3", "Ka1", 2957.5, 0.599456),
+    line(18, "K", "K-M2", "Kb3", 3190.0, 0.032738),
+    line(18, "K", "K-M3", "Kb1", 3190.2, 0.0649435),
+    line(18, "L1", "L1-M2", "Lb4", 310.4, 0.413833),
+    line(18, "L1", "L1-M3", "Lb3", 310.6, 0.586166),
+    line(18, "L2", "L2-M1", "Ln", 221.3, 1.0),
+    line(18, "L3", "L3-M1", "Ll", 219.1, 1.0),
+    line(19, "K", "K-L1", "Ka3", 3229.8, 0.000206063),
+    line(19, "K", "K-L2", "Ka2", 3311.1, 0.299582),
+    line(19, "K", "K-L3", "Ka1", 3313.8, 0.592645),
+    line(19, "K", "K-M2", "Kb3", 3590.1, 0.0360559),
+    line(19, "K", "K-M3", "Kb1", 3590.1, 0.0715111),
+    line(19, "L1", "L1-M2", "Lb4", 360.3, 0.413833),
+    line(19, "L1", "L1-M3", "Lb3", 360.3, 0.586166),
+    line(19, "L2", "L2-M1", "Ln", 262.5, 1.0),
+    line(19, "L3", "L3-M1", "Ll", 259.8, 1.0),
+    line(20, "K", "K-L1", "Ka3", 3600.1, 0.000214824),
+    line(20, "K", "K-L2", "Ka2", 3688.8, 0.297056),
+    line(20, "K", "K-L3", "Ka1", 3692.3, 0.58695),
+    line(20, "K", "K-M2", "Kb3", 4013.1, 0.0388138),
+    line(20, "K", "K-M3", "Kb1", 4013.1, 0.0769657),
+    line(20, "L1", "L1-M2", "Lb4", 413.0, 0.413833),
+    line(20, "L1", "L1-M3", "Lb3", 413.0, 0.586166),
+    line(20, "L2", "L2-M1", "Ln", 305.4, 1.0),
+    line(20, "L3", "L3-M1", "Ll", 301.9, 1.0),
+    line(21, "K", "K-L1", "Ka3", 3994.0, 0.000224936),
+    line(21, "K", "K-L2", "Ka2", 4088.4, 0.296666),
+    line(21, "K", "K-L3", "Ka1", 4093.3, 0.585314),
+    line(21, "K", "K-M2", "Kb3", 4463.7, 0.0393233),
+    line(21, "K", "K-M3", "Kb1", 4463.7, 0.077891),
+    line(21, "K", "K-M4,5", "Kb5", 4492.0, 0.000580163),
+    line(21, "L1", "L1-M2", "Lb4", 469.7, 0.413833),
+    line(21, "L1", "L1-M3", "Lb3", 469.7, 0.586166),
+    line(21, "L2", "L2-M1", "Ln", 352.5, 0.0828801),
+    line(21, "L2", "L2-M4", "Lb1", 403.6, 0.91712),
+    line(21, "L3", "L3-M1", "Ll", 347.6, 0.526066),
+    line(21, "L3", "L3-M4", "La2", 398.7, 0.473934),
+    line(22, "K", "K-L1", "Ka3", 4405.1, 0.000235265),
+    line(22, "K", "K-L2", "Ka2", 4505.8, 0.296623),
+    line(22, "K", "K-L3", "Ka1", 4512.2, 0.584363),
+    line(22, "K", "K-M2", "Kb3", 4933.4, 0.0398769),
+    line(22, "K", "K-M3", "Kb1", 4933.4, 0.0789017),
+    line(22, "L1", "L1-M2", "Lb4", 528.3, 0.413833),
+    line(22, "L1", "L1-M3", "Lb3", 528.3, 0.586166),
+    line(22, "L2", "L2-M1", "Ln", 401.5, 1.0),
+    line(22, "L3", "L3-M1", "Ll", 395.1, 0.108814),
+    line(22, "L3", "L3-M5", "La1", 453.8, 0.891186),
+    line(23, "K", "K-L1", "Ka3", 4838.3, 0.000245575),
+    line(23, "K", "K-L2", "Ka2", 4945.2, 0.296569),
+    line(23, "K", "K-L3", "Ka1", 4952.9, 0.583453),
+    line(23, "K", "K-M2", "Kb3", 5427.8, 0.040229),
+    line(23, "K", "K-M3", "Kb1", 5427.8, 0.0795039),
+    line(23, "L1", "L1-M2", "Lb4", 589.5, 0.413833),
+    line(23, "L1", "L1-M3", "Lb3", 589.5, 0.586166),
+    line(23, "L2", "L2-M1", "Ln", 453.5, 1.0),
+    line(23, "L3", "L3-M1", "Ll", 445.8, 0.108814),
+    line(23, "L3", "L3-M5", "La1", 512.1, 0.891186),
+    line(24, "K", "K-L1", "Ka3", 5293.0, 0.000255759),
+    line(24, "K", "K-L2", "Ka2", 5405.2, 0.296464),
+    line(24, "K", "K-L3", "Ka1", 5414.9, 0.58233),
+    line(24, "K", "K-M2", "Kb3", 5946.8, 0.0404693),
+    line(24, "K", "K-M3", "Kb1", 5946.8, 0.079821),
+    line(24, "K", "K-M4,5", "Kb5", 5989.0, 0.000659663),
+    line(24, "L1", "L1-M2", "Lb4", 653.8, 0.413833),
+    line(24, "L1", "L1-M3", "Lb3", 653.8, 0.586166),
+    line(24, "L2", "L2-M1", "Ln", 509.7, 0.077729),
+    line(24, "L2", "L2-M4", "Lb1", 583.8, 0.922271),
+    line(24, "L3", "L3-M1", "Ll", 500.0, 0.0990991),
+    line(24, "L3", "L3-M4", "La2", 574.1, 0.0892785),
+    line(24, "L3", "L3-M5", "La1", 574.1, 0.811622),
+    line(25, "K", "K-L1", "Ka3", 5769.9, 0.000266057),
+    line(25, "K", "K-L2", "Ka2", 5889.1, 0.29653),
+    line(25, "K", "K-L3", "Ka1", 5900.3, 0.581545),
+    line(25, "K", "K-M2", "Kb3", 6491.8, 0.0407149),
+    line(25, "K", "K-M3", "Kb1", 6491.8, 0.0802581),
+    line(25, "K", "K-M4,5", "Kb5", 6539.0, 0.000686223),
+    line(25, "L1", "L1-M2", "Lb4", 721.9, 0.413833),
+    line(25, "L1", "L1-M3", "Lb3", 721.9, 0.586166),
+    line(25, "L2", "L2-M1", "Ln", 567.6, 0.0759991),
+    line(25, "L2", "L2-M4", "Lb1", 649.9, 0.924001),
+    line(25, "L3", "L3-M1", "Ll", 556.4, 0.0990991),
+    line(25, "L3", "L3-M4", "La2", 638.7, 0.0892785),
+    line(25, "L3", "L3-M5", "La1", 638.7, 0.811622),
+    line(26, "K", "K-L1", "Ka3", 6267.4, 0.000276407),
+    line(26, "K", "K-L2", "Ka2", 6392.1, 0.296681),
+    line(26, "K", "K-L3", "Ka1", 6405.2, 0.580931),
+    line(26, "K", "K-M2", "Kb3", 7059.3, 0.0408902),
+    line(26, "K", "K-M3", "Kb1", 7059.3, 0.0805085),
+    line(26, "K", "K-M4,5", "Kb5", 7112.0, 0.000712918),
+    line(26, "L1", "L1-M2", "Lb4", 791.9, 0.413833),
+    line(26, "L1", "L1-M3", "Lb3", 791.9, 0.586166),
+    line(26, "L2", "L2-M1", "Ln", 628.6, 0.0742626),
+    line(26, "L2", "L2-M4", "Lb1", 719.9, 0.925737),
+    line(26, "L3", "L3-M1", "Ll", 615.5, 0.0990357),
+    line(26, "L3", "L3-M4", "La2", 706.8, 0.0892848),
+    line(26, "L3", "L3-M5", "La1", 706.8, 0.81168),
+    line(27, "K", "K-L1", "Ka3", 6783.9, 0.000286752),
+    line(27, "K", "K-L2", "Ka2", 6915.8, 0.296879),
+    line(27, "K", "K-L3", "Ka1", 6930.9, 0.580351),
+    line(27, "K", "K-M2", "Kb3", 7650.1, 0.0410439),
+    line(27, "K", "K-M3", "Kb1", 7649.1, 0.0806997),
+    line(27, "K", "K-M4,5", "Kb5", 7709.0, 0.0007396),
+    line(27, "L1", "L1-M2", "Lb4", 866.2, 0.413833),
+    line(27, "L1", "L1-M3", "Lb3", 865.2, 0.586166),
+    line(27, "L2", "L2-M1", "Ln", 692.2, 0.0725197),
+    line(27, "L2", "L2-M4", "Lb1", 793.2, 0.92748),
+    line(27, "L3", "L3-M1", "Ll", 677.1, 0.0853871),
+    line(27, "L3", "L3-M4", "La2", 778.1, 0.0906373),
+    line(27, "L3", "L3-M5", "La1", 778.1, 0.823976),
+    line(28, "K", "K-L1", "Ka3", 7324.4, 0.000297076),
+    line(28, "K", "K-L2", "Ka2", 7463.0, 0.297076),
+    line(28, "K", "K-L3", "Ka1", 7480.3, 0.579773),
+    line(28, "K", "K-M2", "Kb3", 8265.0, 0.0411974),
+    line(28, "K", "K-M3", "Kb1", 8266.8, 0.0808903),
+    line(28, "K", "K-M4,5", "Kb5", 8333.0, 0.000766228),
+    line(28, "L1", "L1-M2", "Lb4", 940.6, 0.413833),
+    line(28, "L1", "L1-M3", "Lb3", 942.4, 0.586166),
+    line(28, "L2", "L2-M1", "Ln", 759.2, 0.0685204),
+    line(28, "L2", "L2-M4", "Lb1", 870.0, 0.93148),
+    line(28, "L3", "L3-M1", "Ll", 741.9, 0.0750286),
+    line(28, "L3", "L3-M4", "La2", 852.7, 0.0916638),
+    line(28, "L3", "L3-M5", "La1", 852.7, 0.833308),
+    line(29, "K", "K-L1", "Ka3", 7882.3, 0.000307379),
+    line(29, "K", "K-L2", "Ka2", 8026.7, 0.297301),
+    line(29, "K", "K-L3", "Ka1", 8046.3, 0.579196),
+    line(29, "K", "K-M2", "Kb3", 8901.7, 0.041368),
+    line(29, "K", "K-M3", "Kb1", 8903.9, 0.0810343),
+    line(29, "K", "K-M4,5", "Kb5", 8979.0, 0.000792804),
+    line(29, "L1", "L1-M2", "Lb4", 1019.4, 0.413833),
+    line(29, "L1", "L1-M3", "Lb3", 1021.6, 0.586166),
+    line(29, "L2", "L2-M1", "Ln", 829.8, 0.06619),
+    line(29, "L2", "L2-M4", "Lb1", 952.3, 0.93381),
+    line(29, "L3", "L3-M1", "Ll", 810.2, 0.0673401),
+    line(29, "L3", "L3-M4", "La2", 932.7, 0.0924258),
+    line(29, "L3", "L3-M5", "La1", 932.7, 0.840234),
+    line(30, "K", "K-L1", "Ka3", 8462.8, 0.000317663),
+    line(30, "K", "K-L2", "Ka2", 8614.1, 0.297527),
+    line(30, "K", "K-L3", "Ka1", 8637.2, 0.57862),
+    line(30, "K", "K-M2", "Kb3", 9567.6, 0.0414902),
+    line(30, "K", "K-M3", "Kb1", 9570.4, 0.0812259),
+    line(30, "K", "K-M4,5", "Kb5", 9648.8, 0.000819326),
+    line(30, "L1", "L1-M2", "Lb4", 1104.8, 0.413833),
+    line(30, "L1", "L1-M3", "Lb3", 1107.6, 0.586166),
+    line(30, "L2", "L2-M1", "Ln", 905.1, 0.0639247),
+    line(30, "L2", "L2-M4", "Lb1", 1034.7, 0.936075),
+    line(30, "L3", "L3-M1", "Ll", 882.0, 0.0617203),
+    line(30, "L3", "L3-M4", "La2", 1011.6, 0.0929827),
+    line(30, "L3", "L3-M5", "La1", 1011.7, 0.845297),
+    line(31, "K", "K-L1", "Ka3", 9068.0, 0.000327291),
+    line(31, "K", "K-L2", "Ka2", 9223.8, 0.296859),
+    line(31, "K", "K-L3", "Ka1", 9250.6, 0.576928),
+    line(31, "K", "K-M2", "Kb3", 10263.5, 0.0422682),
+    line(31, "K", "K-M3", "Kb1", 10267.0, 0.0827734),
+    line(31, "K", "K-M4,5", "Kb5", 10348.3, 0.000844162),
+    line(31, "L1", "L1-M2", "Lb4", 1195.5, 0.376291),
+    line(31, "L1", "L1-M3", "Lb3", 1199.0, 0.532989),
+    line(31, "L1", "L1-N3", "Lg3", 1299.0, 0.0907201),
+    line(31, "L2", "L2-M1", "Ln", 983.7, 0.0617242),
+    line(31, "L2", "L2-M4", "Lb1", 1124.5, 0.938276),
+    line(31, "L3", "L3-M1", "Ll", 956.9, 0.0576036),
+    line(31, "L3", "L3-M4", "La2", 1097.7, 0.0933906),
+    line(31, "L3", "L3-M5", "La1", 1097.7, 0.849006),
+    line(32, "K", "K-L1", "Ka3", 9688.4, 0.000335999),
+    line(32, "K", "K-L2", "Ka2", 9854.9, 0.295433),
+    line(32, "K", "K-L3", "Ka1", 9886.0, 0.573768),
+    line(32, "K", "K-M2", "Kb3", 10978.1, 0.0429308),
+    line(32, "K", "K-M3", "Kb1", 10982.2, 0.0840956),
+    line(32, "K", "K-M4,5", "Kb5", 11073.2, 0.00086662),
+    line(32, "K", "K-N2,3", "Kb2", 11103.0, 0.00256911),
+    line(32, "L1", "L1-M2", "Lb4", 1289.7, 0.381622),
+    line(32, "L1", "L1-M3", "Lb3", 1293.8, 0.540541),
+    line(32, "L1", "L1-N2", "Lg2", 1414.6, 0.0778378),
+    line(32, "L2", "L2-M1", "Ln", 1068.0, 0.0595885),
+    line(32, "L2", "L2-M4", "Lb1", 1218.3, 0.940412),
+    line(32, "L3", "L3-M1", "Ll", 1036.9, 0.0544969),
+    line(32, "L3", "L3-M4", "La2", 1187.2, 0.0936926),
+    line(32, "L3", "L3-M5", "La1", 1187.8, 0.851751),
+    line(32, "L3", "L3-N1", "Lb6", 1217.0, 5.96223e-05),
+    line(32, "M4", "M4,5-N2,3", "Mz", 29.8, 1.0),
+    line(33, "K", "K-L1", "Ka3", 10340.0, 0.000344737),
+    line(33, "K", "K-L2", "Ka2", 10507.9, 0.29416),
+    line(33, "K", "K-L3", "Ka1", 10543.4, 0.570852),
+    line(33, "K", "K-M2", "Kb3", 11720.8, 0.0436747),
+    line(33, "K", "K-M3", "Kb1", 11725.8, 0.085419),
+    line(33, "K", "K-M4,5", "Kb5", 11825.3, 0.000889159),
+    line(33, "K", "K-N2,3", "Kb2", 11867.0, 0.00466066),
+    line(33, "L1", "L1-M2", "Lb4", 1380.8, 0.34796),
+    line(33, "L1", "L1-M3", "Lb3", 1385.8, 0.492862),
+    line(33, "L1", "L1-N2", "Lg2", 1527.0, 0.0717114),
+    line(33, "L1", "L1-N3", "Lg3", 1527.0, 0.0874663),
+    line(33, "L2", "L2-M1", "Ln", 1154.4, 0.0575174),
+    line(33, "L2", "L2-M4", "Lb1", 1317.4, 0.942483),
+    line(33, "L3", "L3-M1", "Ll", 1118.9, 0.0519897),
+    line(33, "L3", "L3-M4", "La2", 1281.9, 0.0939148),
+    line(33, "L3", "L3-M5", "La1", 1281.9, 0.853771),
+    line(33, "L3", "L3-N1", "Lb6", 1323.6, 0.000324433),
+    line(33, "M4", "M4,5-N2,3", "Mz", 41.7, 1.0),
+    line(34, "K", "K-L1", "Ka3", 11006.0, 0.000353087),
+    line(34, "K", "K-L2", "Ka2", 11183.7, 0.292707),
+    line(34, "K", "K-L3", "Ka1", 11224.1, 0.567481),
+    line(34, "K", "K-M2", "Kb3", 12491.5, 0.0444618),
+    line(34, "K", "K-M3", "Kb1", 12497.3, 0.0869073),
+    line(34, "K", "K-M4,5", "Kb5", 12602.5, 0.000910694),
+    line(34, "K", "K-N2,3", "Kb2", 12658.0, 0.00717932),
+    line(34, "L1", "L1-M2", "Lb4", 1485.5, 0.347407),
+    line(34, "L1", "L1-M3", "Lb3", 1491.3, 0.492079),
+    line(34, "L1", "L1-N2", "Lg2", 1652.0, 0.0723355),
+    line(34, "L1", "L1-N3", "Lg3", 1652.0, 0.0881785),
+    line(34, "L2", "L2-M1", "Ln", 1244.7, 0.0555107),
+    line(34, "L2", "L2-M4", "Lb1", 1418.8, 0.944489),
+    line(34, "L3", "L3-M1", "Ll", 1204.3, 0.0497962),
+    line(34, "L3", "L3-M4", "La2", 1378.4, 0.0941058),
+    line(34, "L3", "L3-M5", "La1", 1379.3, 0.855508),
+    line(34, "L3", "L3-N1", "Lb6", 1433.9, 0.0005903),
+    line(34, "M4", "M4,5-N2,3", "Mz", 55.5, 1.0),
+    line(35, "K", "K-L1", "Ka3", 11692.0, 0.000361094),
+    line(35, "K", "K-L2", "Ka2", 11878.0, 0.292088),
+    line(35, "K", "K-L3", "Ka1", 11924.0, 0.563768),
+    line(35, "K", "K-M2", "Kb3", 13285.0, 0.0449442),
+    line(35, "K", "K-M3", "Kb1", 13292.0, 0.0878503),
+    line(35, "K", "K-M4,5", "Kb5", 13404.0, 0.000931345),
+    line(35, "K", "K-N2,3", "Kb2", 13474.0, 0.0100565),
+    line(35, "L1", "L1-M2", "Lb4", 1593.0, 0.346842),
+    line(35, "L1", "L1-M3", "Lb3", 1600.0, 0.491277),
+    line(35, "L1", "L1-N2", "Lg2", 1782.0, 0.0729547),
+    line(35, "L1", "L1-N3", "Lg3", 1782.0, 0.0889264),
+    line(35, "L2", "L2-M1", "Ln", 1339.0, 0.0535683),
+    line(35, "L2", "L2-M4", "Lb1", 1526.0, 0.946432),
+    line(35, "L3", "L3-M1", "Ll", 1293.0, 0.047727),
+    line(35, "L3", "L3-M4", "La2", 1480.0, 0.0942845),
+    line(35, "L3", "L3-M5", "La1", 1481.0, 0.857131),
+    line(35, "L3", "L3-N1", "Lb6", 1550.0, 0.000857132),
+    line(35, "M4", "M4,5-N2,3", "Mz", 70.0, 1.0),
+    line(36, "K", "K-L1", "Ka3", 12405.0, 0.000369929),
+    line(36, "K", "K-L2", "Ka2", 12595.1, 0.291204),
+    line(36, "K", "K-L3", "Ka1", 12647.6, 0.561519),
+    line(36, "K", "K-M2", "Kb3", 14103.8, 0.0448979),
+    line(36, "K", "K-M3", "Kb1", 14111.6, 0.0878456),
+    line(36, "K", "K-M4,5", "Kb5", 14231.0, 0.000954133),
+    line(36, "K", "K-N2,3", "Kb2", 14311.9, 0.0132096),
+    line(36, "L1", "L1-M2", "Lb4", 1698.8, 0.346263),
+    line(36, "L1", "L1-M3", "Lb3", 1706.6, 0.490458),
+    line(36, "L1", "L1-N2", "Lg2", 1906.9, 0.0735687),
+    line(36, "L1", "L1-N3", "Lg3", 1906.9, 0.0897098),
+    line(36, "L2", "L2-M1", "Ln", 1438.1, 0.0516897),
+    line(36, "L2", "L2-M4", "Lb1", 1635.9, 0.94831),
+    line(36, "L3", "L3-M1", "Ll", 1385.6, 0.0457049),
+    line(36, "L3", "L3-M4", "La2", 1583.4, 0.0944583),
+    line(36, "L3", "L3-M5", "La1", 1584.6, 0.858712),
+    line(36, "L3", "L3-N1", "Lb6", 1650.9, 0.00112491),
+    line(36, "M4", "M4,5-N2,3", "Mz", 80.9, 1.0),
+    line(37, "K", "K-L1", "Ka3", 13135.0, 0.000378249),
+    line(37, "K", "K-L2", "Ka2", 13336.0, 0.290209),
+    line(37, "K", "K-L3", "Ka1", 13396.0, 0.558632),
+    line(37, "K", "K-M2", "Kb3", 14951.3, 0.0454634),
+    line(37, "K", "K-M3", "Kb1", 14960.9, 0.0889174),
+    line(37, "K", "K-M4,5", "Kb5", 15087.0, 0.000975594),
+    line(37, "K", "K-N2,3", "Kb2", 15183.7, 0.0154247),
+    line(37, "L1", "L1-M2", "Lb4", 1816.3, 0.345673),
+    line(37, "L1", "L1-M3", "Lb3", 1825.9, 0.489621),
+    line(37, "L1", "L1-N2", "Lg2", 2048.7, 0.0741776),
+    line(37, "L1", "L1-N3", "Lg3", 2049.7, 0.0905282),
+    line(37, "L2", "L2-M1", "Ln", 1537.3, 0.0498747),
+    line(37, "L2", "L2-M4", "Lb1", 1751.0, 0.950125),
+    line(37, "L3", "L3-M1", "Ll", 1477.3, 0.0437584),
+    line(37, "L3", "L3-M4", "La2", 1691.0, 0.0946246),
+    line(37, "L3", "L3-M5", "La1", 1692.0, 0.860223),
+    line(37, "L3", "L3-N1", "Lb6", 1773.5, 0.00139356),
+    line(37, "M4", "M4,5-N2,3", "Mz", 96.7, 1.0),
+    line(38, "K", "K-L1", "Ka3", 13889.0, 0.000386549),
+    line(38, "K", "K-L2", "Ka2", 14098.0, 0.289328),
+    line(38, "K", "K-L3", "Ka1", 14165.0, 0.555866),
+    line(38, "K", "K-M2", "Kb3", 15824.7, 0.0459937),
+    line(38, "K", "K-M3", "Kb1", 15835.0, 0.0899192),
+    line(38, "K", "K-M4,5", "Kb5", 15969.0, 0.000997002),
+    line(38, "K", "K-N2,3", "Kb2", 16083.7, 0.0175089),
+    line(38, "L1", "L1-M2", "Lb4", 1935.7, 0.34507),
+    line(38, "L1", "L1-M3", "Lb3", 1946.0, 0.488767),
+    line(38, "L1", "L1-N2", "Lg2", 2194.7, 0.0747814),
+    line(38, "L1", "L1-N3", "Lg3", 2195.9, 0.0913816),
+    line(38, "L2", "L2-M1", "Ln", 1648.3, 0.0481229),
+    line(38, "L2", "L2-M4", "Lb1", 1871.0, 0.951877),
+    line(38, "L3", "L3-M1", "Ll", 1581.3, 0.0420478),
+    line(38, "L3", "L3-M4", "La2", 1804.0, 0.0947674),
+    line(38, "L3", "L3-M5", "La1", 1805.8, 0.861522),
+    line(38, "L3", "L3-N1", "Lb6", 1901.1, 0.00166274),
+    line(38, "M4", "M4,5-N2,3", "Mz", 114.7, 1.0),
+    line(39, "K", "K-L1", "Ka3", 14665.0, 0.000394961),
+    line(39, "K", "K-L2", "Ka2", 14882.0, 0.288598),
+    line(39, "K", "K-L3", "Ka1", 14958.0, 0.553399),
+    line(39, "K", "K-M2", "Kb3", 16727.4, 0.0465448),
+    line(39, "K", "K-M3", "Kb1", 16739.2, 0.0909523),
+    line(39, "K", "K-M4,5", "Kb5", 16880.3, 0.0010187),
+    line(39, "K", "K-N2,3", "Kb2", 17013.6, 0.0188074),
+    line(39, "K", "K-N4,5", "Kb4", 17038.0, 0.00028489),
+    line(39, "L1", "L1-M2", "Lb4", 2062.4, 0.344455),
+    line(39, "L1", "L1-M3", "Lb3", 2074.2, 0.487896),
+    line(39, "L1", "L1-N2", "Lg2", 2348.6, 0.0753799),
+    line(39, "L1", "L1-N3", "Lg3", 2349.9, 0.0922695),
+    line(39, "L2", "L2-M1", "Ln", 1764.0, 0.0453072),
+    line(39, "L2", "L2-M4", "Lb1", 1998.3, 0.930427),
+    line(39, "L2", "L2-N4", "Lg1", 2156.0, 0.0242655),
+    line(39, "L3", "L3-M1", "Ll", 1688.0, 0.0408478),
+    line(39, "L3", "L3-M4", "La2", 1922.3, 0.0948597),
+    line(39, "L3", "L3-M5", "La1", 1924.2, 0.862361),
+    line(39, "L3", "L3-N1", "Lb6", 2036.2, 0.00193169),
+    line(39, "M2", "M2-N4", "M2-N4", 310.6, 1.0),
+    line(39, "M4", "M4,5-N2,3", "Mz", 133.3, 1.0),
+    line(40, "K", "K-L1", "Ka3", 15466.0, 0.00040341),
+    line(40, "K", "K-L2", "Ka2", 15691.0, 0.287953),
+    line(40, "K", "K-L3", "Ka1", 15775.0, 0.551106),
+    line(40, "K", "K-M2", "Kb3", 17654.5, 0.0471046),
+    line(40, "K", "K-M3", "Kb1", 17668.2, 0.0920012),
+    line(40, "K", "K-M4,5", "Kb5", 17816.9, 0.00104049),
+    line(40, "K", "K-N2,3", "Kb2", 17969.5, 0.0200999),
+    line(40, "K", "K-N4,5", "Kb4", 17998.0, 0.000290984),
+    line(40, "L1", "L1-M2", "Lb4", 2188.5, 0.343827),
+    line(40, "L1", "L1-M3", "Lb3", 2202.2, 0.487008),
+    line(40, "L1", "L1-N2", "Lg2", 2503.5, 0.0759732),
+    line(40, "L1", "L1-N3", "Lg3", 2504.9, 0.0931916),
+    line(40, "L2", "L2-M1", "Ln", 1876.7, 0.0434867),
+    line(40, "L2", "L2-M4", "Lb1", 2125.9, 0.92704),
+    line(40, "L2", "L2-N4", "Lg1", 2307.0, 0.0294738),
+    line(40, "L3", "L3-M1", "Ll", 1792.7, 0.0393706),
+    line(40, "L3", "L3-M4", "La2", 2041.9, 0.0950279),
+    line(40, "L3", "L3-M5", "La1", 2044.2, 0.855441),
+    line(40, "L3", "L3-N1", "Lb6", 2172.4, 0.00218137),
+    line(40, "L3", "L3-N4,5", "Lb2,15", 2223.0, 0.00797933),
+    line(40, "M2", "M2-N4", "M2-N4", 343.5, 1.0),
+    line(40, "M4", "M4,5-N2,3", "Mz", 152.6, 1.0),
+    line(41, "K", "K-L1", "Ka3", 16288.0, 0.000412026),
+    line(41, "K", "K-L2", "Ka2", 16521.0, 0.287534),
+    line(41, "K", "K-L3", "Ka1", 16615.0, 0.549149),
+    line(41, "K", "K-M2", "Kb3", 18609.9, 0.0476319),
+    line(41, "K", "K-M3", "Kb1", 18625.4, 0.0929857),
+    line(41, "K", "K-M4,5", "Kb5", 18781.0, 0.00106271),
+    line(41, "K", "K-N2,3", "Kb2", 18953.4, 0.0209274),
+    line(41, "K", "K-N4,5", "Kb4", 18986.0, 0.000297199),
+    line(41, "L1", "L1-M2", "Lb4", 2321.9, 0.343188),
+    line(41, "L1", "L1-M3", "Lb3", 2337.4, 0.486103),
+    line(41, "L1", "L1-N2", "Lg2", 2665.4, 0.0765612),
+    line(41, "L1", "L1-N3", "Lg3", 2667.2, 0.0941478),
+    line(41, "L2", "L2-M1", "Ln", 1998.4, 0.0415113),
+    line(41, "L2", "L2-M4", "Lb1", 2260.0, 0.91845),
+    line(41, "L2", "L2-N4", "Lg1", 2465.0, 0.0400383),
+    line(41, "L3", "L3-M1", "Ll", 1904.4, 0.0379554),
+    line(41, "L3", "L3-M4", "La2", 2166.0, 0.0933449),
+    line(41, "L3", "L3-M5", "La1", 2168.7, 0.840183),
+    line(41, "L3", "L3-N1", "Lb6", 2314.6, 0.00240292),
+    line(41, "L3", "L3-N4,5", "Lb2,15", 2371.0, 0.0261141),
+    line(41, "M2", "M2-N4", "M2-N4", 376.1, 1.0),
+    line(41, "M4", "M4,5-N2,3", "Mz", 172.4, 1.0),
+    line(42, "K", "K-L1", "Ka3", 17134.0, 0.000420582),
+    line(42, "K", "K-L2", "Ka2", 17375.0, 0.287118),
+    line(42, "K", "K-L3", "Ka1", 17480.0, 0.547205),
+    line(42, "K", "K-M2", "Kb3", 19588.4, 0.0481559),
+    line(42, "K", "K-M3", "Kb1", 19606.0, 0.0939628),
+    line(42, "K", "K-M4,5", "Kb5", 19768.9, 0.00108478),
+    line(42, "K", "K-N2,3", "Kb2", 19962.4, 0.021749),
+    line(42, "K", "K-N4,5", "Kb4", 20000.0, 0.00030337),
+    line(42, "L1", "L1-M2", "Lb4", 2454.4, 0.341952),
+    line(42, "L1", "L1-M3", "Lb3", 2472.0, 0.485613),
+    line(42, "L1", "L1-N2", "Lg2", 2828.4, 0.0772124),
+    line(42, "L1", "L1-N3", "Lg3", 2830.5, 0.0952223),
+    line(42, "L2", "L2-M1", "Ln", 2118.7, 0.0396647),
+    line(42, "L2", "L2-M4", "Lb1", 2393.9, 0.910631),
+    line(42, "L2", "L2-N4", "Lg1", 2625.0, 0.0497043),
+    line(42, "L3", "L3-M1", "Ll", 2013.7, 0.0366893),
+    line(42, "L3", "L3-M4", "La2", 2288.9, 0.0918286),
+    line(42, "L3", "L3-M5", "La1", 2292.1, 0.82643),
+    line(42, "L3", "L3-N1", "Lb6", 2456.8, 0.00261978),
+    line(42, "L3", "L3-N4,5", "Lb2,15", 2520.0, 0.0424322),
+    line(42, "M2", "M2-N4", "M2-N4", 411.6, 1.0),
+    line(42, "M3", "M3-N5", "Mg", 394.0, 1.0),
+    line(42, "M4", "M4,5-N2,3", "Mz", 193.5, 1.0),
+    line(43, "K", "K-L1", "Ka3", 18001.0, 0.000429196),
+    line(43, "K", "K-L2", "Ka2", 18251.0, 0.286818),
+    line(43, "K", "K-L3", "Ka1", 18367.0, 0.545427),
+    line(43, "K", "K-M2", "Kb3", 20596.4, 0.048591),
+    line(43, "K", "K-M3", "Kb1", 20626.3, 0.0947637),
+    line(43, "K", "K-M4,5", "Kb5", 20786.4, 0.001107),
+    line(43, "K", "K-N2,3", "Kb2", 21001.7, 0.0225547),
+    line(43, "K", "K-N4,5", "Kb4", 21044.0, 0.000309584),
+    line(43, "L1", "L1-M2", "Lb4", 2595.4, 0.336978),
+    line(43, "L1", "L1-M3", "Lb3", 2625.3, 0.487847),
+    line(43, "L1", "L1-N2", "Lg2", 3000.7, 0.0782994),
+    line(43, "L1", "L1-N3", "Lg3", 3003.1, 0.0968764),
+    line(43, "L2", "L2-M1", "Ln", 2249.0, 0.0379374),
+    line(43, "L2", "L2-M4", "Lb1", 2535.4, 0.903511),
+    line(43, "L2", "L2-N4", "Lg1", 2793.0, 0.058552),
+    line(43, "L3", "L3-M1", "Ll", 2133.0, 0.0355585),
+    line(43, "L3", "L3-M4", "La2", 2419.4, 0.0904627),
+    line(43, "L3", "L3-M5", "La1", 2423.1, 0.814033),
+    line(43, "L3", "L3-N1", "Lb6", 2607.5, 0.00283283),
+    line(43, "L3", "L3-N4,5", "Lb2,15", 2677.0, 0.0571129),
+    line(43, "M2", "M2-N4", "M2-N4", 447.6, 1.0),
+    line(43, "M3", "M3-N5", "Mg", 417.7, 1.0),
+    line(43, "M4", "M4,5-N2,3", "Mz", 215.3, 1.0),
+    line(44, "K", "K-L1", "Ka3", 18893.0, 0.000437755),
+    line(44, "K", "K-L2", "Ka2", 19150.0, 0.28652),
+    line(44, "K", "K-L3", "Ka1", 19279.0, 0.54366),
+    line(44, "K", "K-M2", "Kb3", 21633.5, 0.0490237),
+    line(44, "K", "K-M3", "Kb1", 21655.6, 0.095559),
+    line(44, "K", "K-M4,5", "Kb5", 21832.8, 0.00112907),
+    line(44, "K", "K-N2,3", "Kb2", 22070.7, 0.0233552),
+    line(44, "K", "K-N4,5", "Kb4", 22117.0, 0.000315758),
+    line(44, "L1", "L1-M2", "Lb4", 2740.5, 0.332221),
+    line(44, "L1", "L1-M3", "Lb3", 2762.6, 0.489877),
+    line(44, "L1", "L1-N2", "Lg2", 3177.7, 0.0793601),
+    line(44, "L1", "L1-N3", "Lg3", 3180.8, 0.0985423),
+    line(44, "L2", "L2-M1", "Ln", 2380.9, 0.0363209),
+    line(44, "L2", "L2-M4", "Lb1", 2682.8, 0.897026),
+    line(44, "L2", "L2-N4", "Lg1", 2967.0, 0.0666532),
+    line(44, "L3", "L3-M1", "Ll", 2251.9, 0.0345507),
+    line(44, "L3", "L3-M4", "La2", 2553.8, 0.0892327),
+    line(44, "L3", "L3-M5", "La1", 2558.0, 0.802863),
+    line(44, "L3", "L3-N1", "Lb6", 2763.0, 0.00304285),
+    line(44, "L3", "L3-N4,5", "Lb2,15", 2838.0, 0.070311),
+    line(44, "M2", "M2-N4", "M2-N4", 483.5, 1.0),
+    line(44, "M3", "M3-N5", "Mg", 461.4, 1.0),
+    line(44, "M4", "M4,5-N2,3", "Mz", 237.9, 1.0),
+    line(45, "K", "K-L1", "Ka3", 19808.0, 0.000446258),
+    line(45, "K", "K-L2", "Ka2", 20074.0, 0.286223),
+    line(45, "K", "K-L3", "Ka1", 20216.0, 0.541905),
+    line(45, "K", "K-M2", "Kb3", 22698.7, 0.0494539),
+    line(45, "K", "K-M3", "Kb1", 22723.5, 0.0963488),
+    line(45, "K", "K-M4,5", "Kb5", 22908.1, 0.00115101),
+    line(45, "K", "K-N2,3", "Kb2", 23169.5, 0.0241505),
+    line(45, "K", "K-N4,5", "Kb4", 23220.0, 0.000321891),
+    line(45, "L1", "L1-M2", "Lb4", 2890.7, 0.327692),
+    line(45, "L1", "L1-M3", "Lb3", 2915.5, 0.491698),
+    line(45, "L1", "L1-N2", "Lg2", 3361.5, 0.0803926),
+    line(45, "L1", "L1-N3", "Lg3", 3364.7, 0.100218),
+    line(45, "L2", "L2-M1", "Ln", 2517.9, 0.0348076),
+    line(45, "L2", "L2-M4", "Lb1", 2834.1, 0.89112),
+    line(45, "L2", "L2-N4", "Lg1", 3146.0, 0.0740722),
+    line(45, "L3", "L3-M1", "Ll", 2375.9, 0.0336554),
+    line(45, "L3", "L3-M4", "La2", 2692.1, 0.0881263),
+    line(45, "L3", "L3-M5", "La1", 2696.8, 0.792807),
+    line(45, "L3", "L3-N1", "Lb6", 2922.6, 0.00325051),
+    line(45, "L3", "L3-N4,5", "Lb2,15", 3004.0, 0.0821605),
+    line(45, "M2", "M2-N4", "M2-N4", 521.3, 1.0),
+    line(45, "M3", "M3-N5", "Mg", 496.5, 1.0),
+    line(45, "M4", "M4,5-N2,3", "Mz", 261.4, 1.0),
+    line(46, "K", "K-L1", "Ka3", 20746.0, 0.000454707),
+    line(46, "K", "K-L2", "Ka2", 21020.0, 0.285929),
+    line(46, "K", "K-L3", "Ka1", 21177.0, 0.54016),
+    line(46, "K", "K-M2", "Kb3", 23790.1, 0.0498817),
+    line(46, "K", "K-M3", "Kb1", 23817.7, 0.0971331),
+    line(46, "K", "K-M4,5", "Kb5", 24009.5, 0.0011728),
+    line(46, "K", "K-N2,3", "Kb2", 24294.3, 0.0249407),
+    line(46, "K", "K-N4,5", "Kb4", 24350.0, 0.000327985),
+    line(46, "L1", "L1-M2", "Lb4", 3044.1, 0.3234),
+    line(46, "L1", "L1-M3", "Lb3", 3071.7, 0.493304),
+    line(46, "L1", "L1-N2", "Lg2", 3548.3, 0.0813952),
+    line(46, "L1", "L1-N3", "Lg3", 3553.1, 0.1019),
+    line(46, "L2", "L2-M1", "Ln", 2658.4, 0.0333907),
+    line(46, "L2", "L2-M4", "Lb1", 2989.5, 0.885741),
+    line(46, "L2", "L2-N4", "Lg1", 3330.0, 0.0808685),
+    line(46, "L3", "L3-M1", "Ll", 2501.4, 0.0328635),
+    line(46, "L3", "L3-M4", "La2", 2832.5, 0.0871326),
+    line(46, "L3", "L3-M5", "La1", 2837.8, 0.783768),
+    line(46, "L3", "L3-N1", "Lb6", 3085.9, 0.00345642),
+    line(46, "L3", "L3-N4,5", "Lb2,15", 3173.0, 0.0927798),
+    line(46, "M2", "M2-N4", "M2-N4", 559.9, 1.0),
+    line(46, "M3", "M3-N5", "Mg", 532.3, 1.0),
+    line(46, "M4", "M4,5-N2,3", "Mz", 284.8, 1.0),
+    line(47, "K", "K-L1", "Ka3", 21708.0, 0.000463102),
+    line(47, "K", "K-L2", "Ka2", 21990.0, 0.285636),
+    line(47, "K", "K-L3", "Ka1", 22163.0, 0.538428),
+    line(47, "K", "K-M2", "Kb3", 24910.2, 0.0503072),
+    line(47, "K", "K-M3", "Kb1", 24941.0, 0.097912),
+    line(47, "K", "K-M4,5", "Kb5", 25140.0, 0.00119445),
+    line(47, "K", "K-N2,3", "Kb2", 25450.3, 0.0257259),
+    line(47, "K", "K-N4,5", "Kb4", 25514.0, 0.00033404),
+    line(47, "L1", "L1-M2", "Lb4", 3202.2, 0.319354),
+    line(47, "L1", "L1-M3", "Lb3", 3233.0, 0.494692),
+    line(47, "L1", "L1-N2", "Lg2", 3742.3, 0.0823662),
+    line(47, "L1", "L1-N3", "Lg3", 3747.7, 0.103588),
+    line(47, "L2", "L2-M1", "Ln", 2805.0, 0.0320642),
+    line(47, "L2", "L2-M4", "Lb1", 3150.0, 0.880841),
+    line(47, "L2", "L2-N4", "Lg1", 3524.0, 0.0870949),
+    line(47, "L3", "L3-M1", "Ll", 2632.0, 0.0321672),
+    line(47, "L3", "L3-M4", "La2", 2977.0, 0.086242),
+    line(47, "L3", "L3-M5", "La1", 2982.7, 0.775658),
+    line(47, "L3", "L3-N1", "Lb6", 3254.0, 0.0036611),
+    line(47, "L3", "L3-N4,5", "Lb2,15", 3351.0, 0.102272),
+    line(47, "M2", "M2-N4", "M2-N4", 603.8, 1.0),
+    line(47, "M3", "M3-N5", "Mg", 573.0, 1.0),
+    line(47, "M4", "M4,5-N2,3", "Mz", 310.3, 1.0),
+    line(48, "K", "K-L1", "Ka3", 22693.0, 0.000471259),
+    line(48, "K", "K-L2", "Ka2", 22984.0, 0.285291),
+    line(48, "K", "K-L3", "Ka1", 23173.0, 0.536497),
+    line(48, "K", "K-M2", "Kb3", 26058.4, 0.0506376),
+    line(48, "K", "K-M3", "Kb1", 26092.6, 0.0984911),
+    line(48, "K", "K-M4,5", "Kb5", 26299.1, 0.00121549),
+    line(48, "K", "K-N2,3", "Kb2", 26647.1, 0.0270572),
+    line(48, "K", "K-N4,5", "Kb4", 26699.3, 0.000339924),
+    line(48, "L1", "L1-M2", "Lb4", 3365.4, 0.315563),
+    line(48, "L1", "L1-M3", "Lb3", 3399.6, 0.495856),
+    line(48, "L1", "L1-N2", "Lg2", 3954.1, 0.0833038),
+    line(48, "L1", "L1-N3", "Lg3", 3954.1, 0.105278),
+    line(48, "L2", "L2-M1", "Ln", 2955.0, 0.0308225),
+    line(48, "L2", "L2-M4", "Lb1", 3315.1, 0.876377),
+    line(48, "L2", "L2-N4", "Lg1", 3715.3, 0.0928007),
+    line(48, "L3", "L3-M1", "Ll", 2766.0, 0.0315593),
+    line(48, "L3", "L3-M4", "La2", 3126.1, 0.0854459),
+    line(48, "L3", "L3-M5", "La1", 3132.8, 0.7684),
+    line(48, "L3", "L3-N1", "Lb6", 3428.2, 0.00386505),
+    line(48, "L3", "L3-N4,5", "Lb2,15", 3526.3, 0.11073),
+    line(48, "M2", "M2-N4", "M2-N4", 640.9, 1.0),
+    line(48, "M3", "M3-N5", "Mg", 607.7, 1.0),
+    line(48, "M4", "M4,5-N2,3", "Mz", 348.0, 1.0),
+    line(49, "K", "K-L1", "Ka3", 23702.0, 0.000479357),
+    line(49, "K", "K-L2", "Ka2", 24002.0, 0.284949),
+    line(49, "K", "K-L3", "Ka1", 24210.0, 0.534579),
+    line(49, "K", "K-M2", "Kb3", 27236.8, 0.050966),
+    line(49, "K", "K-M3", "Kb1", 27274.7, 0.0990657),
+    line(49, "K", "K-M4,5", "Kb5", 27488.6, 0.00123637),
+    line(49, "K", "K-N2,3", "Kb2", 27866.5, 0.028379),
+    line(49, "K", "K-N4,5", "Kb4", 27922.3, 0.000345766),
+    line(49, "L1", "L1-M2", "Lb4", 3534.8, 0.312033),
+    line(49, "L1", "L1-M3", "Lb3", 3572.7, 0.496793),
+    line(49, "L1", "L1-N2", "Lg2", 4164.5, 0.0842065),
+    line(49, "L1", "L1-N3", "Lg3", 4164.5, 0.106968),
+    line(49, "L2", "L2-M1", "Ln", 3110.8, 0.0296609),
+    line(49, "L2", "L2-M4", "Lb1", 3486.6, 0.872309),
+    line(49, "L2", "L2-N4", "Lg1", 3920.3, 0.0980306),
+    line(49, "L3", "L3-M1", "Ll", 2902.8, 0.0310338),
+    line(49, "L3", "L3-M4", "La2", 3278.6, 0.084737),
+    line(49, "L3", "L3-M5", "La1", 3286.1, 0.761928),
+    line(49, "L3", "L3-N1", "Lb6", 3607.1, 0.00406869),
+    line(49, "L3", "L3-N4,5", "Lb2,15", 3712.3, 0.118233),
+    line(49, "M2", "M2-N4", "M2-N4", 685.5, 1.0),
+    line(49, "M3", "M3-N5", "Mg", 648.4, 1.0),
+    line(49, "M4", "M4,5-N2,3", "Mz", 377.9, 1.0),
+    line(50, "K", "K-L1", "Ka3", 24735.0, 0.000487398),
+    line(50, "K", "K-L2", "Ka2", 25044.0, 0.284609),
+    line(50, "K", "K-L3", "Ka1", 25271.0, 0.532676),
+    line(50, "K", "K-M2", "Kb3", 28443.5, 0.0512925),
+    line(50, "K", "K-M3", "Kb1", 28485.4, 0.0996358),
+    line(50, "K", "K-M4,5", "Kb5", 28706.8, 0.00125711),
+    line(50, "K", "K-N2,3", "Kb2", 29116.4, 0.0296913),
+    line(50, "K", "K-N4,5", "Kb4", 29175.1, 0.000351566),
+    line(50, "L1", "L1-M2", "Lb4", 3708.5, 0.308771),
+    line(50, "L1", "L1-M3", "Lb3", 3750.4, 0.497501),
+    line(50, "L1", "L1-N2", "Lg2", 4381.4, 0.0850726),
+    line(50, "L1", "L1-N3", "Lg3", 4381.4, 0.108655),
+    line(50, "L2", "L2-M1", "Ln", 3271.3, 0.0285748),
+    line(50, "L2", "L2-M4", "Lb1", 3662.8, 0.8686),
+    line(50, "L2", "L2-N4", "Lg1", 4131.1, 0.102826),
+    line(50, "L3", "L3-M1", "Ll", 3044.3, 0.0305854),
+    line(50, "L3", "L3-M4", "La2", 3435.8, 0.0841084),
+    line(50, "L3", "L3-M5", "La1", 3444.1, 0.756179),
+    line(50, "L3", "L3-N1", "Lb6", 3791.9, 0.00427241),
+    line(50, "L3", "L3-N4,5", "Lb2,15", 3904.1, 0.124855),
+    line(50, "M2", "M2-N4", "M2-N4", 731.6, 1.0),
+    line(50, "M3", "M3-N5", "Mg", 690.7, 1.0),
+    line(50, "M4", "M4,5-N2,3", "Mz", 409.6, 1.0),
+    line(51, "K", "K-L1", "Ka3", 25793.0, 0.000495268),
+    line(51, "K", "K-L2", "Ka2", 26111.0, 0.284223),
+    line(51, "K", "K-L3", "Ka1", 26359.0, 0.530664),
+    line(51, "K", "K-M2", "Kb3", 29678.3, 0.0515609),
+    line(51, "K", "K-M3", "Kb1", 29724.6, 0.100099),
+    line(51, "K", "K-M4,5", "Kb5", 29953.5, 0.00127741),
+    line(51, "K", "K-N2,3", "Kb2", 30395.4, 0.0313234),
+    line(51, "K", "K-N4,5", "Kb4", 30457.7, 0.000357243),
+    line(51, "L1", "L1-M2", "Lb4", 3885.3, 0.305785),
+    line(51, "L1", "L1-M3", "Lb3", 3931.6, 0.497976),
+    line(51, "L1", "L1-N2", "Lg2", 4602.4, 0.0859009),
+    line(51, "L1", "L1-N3", "Lg3", 4602.4, 0.110338),
+    line(51, "L2", "L2-M1", "Ln", 3434.0, 0.0275601),
+    line(51, "L2", "L2-M4", "Lb1", 3842.5, 0.865216),
+    line(51, "L2", "L2-N4", "Lg1", 4346.7, 0.107224),
+    line(51, "L3", "L3-M1", "Ll", 3186.0, 0.0302093),
+    line(51, "L3", "L3-M4", "La2", 3594.5, 0.0835541),
+    line(51, "L3", "L3-M5", "La1", 3603.8, 0.7511),
+    line(51, "L3", "L3-N1", "Lb6", 3978.8, 0.00447655),
+    line(51, "L3", "L3-N4,5", "Lb2,15", 4098.7, 0.13066),
+    line(51, "M2", "M2-N4", "M2-N4", 779.4, 1.0),
+    line(51, "M3", "M3-N5", "Mg", 734.3, 1.0),
+    line(51, "M4", "M4,5-N2,3", "Mz", 441.9, 1.0),
+    line(52, "K", "K-L1", "Ka3", 26875.0, 0.000503114),
+    line(52, "K", "K-L2", "Ka2", 27202.0, 0.283914),
+    line(52, "K", "K-L3", "Ka1", 27473.0, 0.528704),
+    line(52, "K", "K-M2", "Kb3", 30943.2, 0.0517887),
+    line(52, "K", "K-M3", "Kb1", 30994.0, 0.100502),
+    line(52, "K", "K-M4,5", "Kb5", 31230.6, 0.00129765),
+    line(52, "K", "K-N2,3", "Kb2", 31710.7, 0.0329278),
+    line(52, "K", "K-N4,5", "Kb4", 31772.1, 0.000362902),
+    line(52, "L1", "L1-M2", "Lb4", 4068.2, 0.303079),
+    line(52, "L1", "L1-M3", "Lb3", 4119.0, 0.498218),
+    line(52, "L1", "L1-N2", "Lg2", 4835.7, 0.0866899),
+    line(52, "L1", "L1-N3", "Lg3", 4835.7, 0.112014),
+    line(52, "L2", "L2-M1", "Ln", 3606.0, 0.0266132),
+    line(52, "L2", "L2-M4", "Lb1", 4028.6, 0.862125),
+    line(52, "L2", "L2-N4", "Lg1", 4570.1, 0.111262),
+    line(52, "L3", "L3-M1", "Ll", 3335.0, 0.0299013),
+    line(52, "L3", "L3-M4", "La2", 3757.6, 0.0830687),
+    line(52, "L3", "L3-M5", "La1", 3768.0, 0.746641),
+    line(52, "L3", "L3-N1", "Lb6", 4171.6, 0.00468144),
+    line(52, "L3", "L3-N4,5", "Lb2,15", 4299.1, 0.135708),
+    line(52, "M2", "M2-N4", "M2-N4", 828.9, 1.0),
+    line(52, "M3", "M3-N5", "Mg", 779.6, 1.0),
+    line(52, "M4", "M4,5-N2,3", "Mz", 480.1, 1.0),
+    line(53, "K", "K-L1", "Ka3", 27981.0, 0.000510903),
+    line(53, "K", "K-L2", "Ka2", 28317.0, 0.283607),
+    line(53, "K", "K-L3", "Ka1", 28612.0, 0.526758),
+    line(53, "K", "K-M2", "Kb3", 32238.0, 0.052015),
+    line(53, "K", "K-M3", "Kb1", 32294.0, 0.100902),
+    line(53, "K", "K-M4,5", "Kb5", 32538.2, 0.00131774),
+    line(53, "K", "K-N2,3", "Kb2", 33046.0, 0.0345205),
+    line(53, "K", "K-N4,5", "Kb4", 33118.4, 0.00036852),
+    line(53, "L1", "L1-M2", "Lb4", 4257.0, 0.300658),
+    line(53, "L1", "L1-M3", "Lb3", 4313.0, 0.498224),
+    line(53, "L1", "L1-N2", "Lg2", 5065.0, 0.0874384),
+    line(53, "L1", "L1-N3", "Lg3", 5065.0, 0.11368),
+    line(53, "L2", "L2-M1", "Ln", 3780.0, 0.0257307),
+    line(53, "L2", "L2-M4", "Lb1", 4221.2, 0.859299),
+    line(53, "L2", "L2-N4", "Lg1", 4801.4, 0.114971),
+    line(53, "L3", "L3-M1", "Ll", 3485.0, 0.0296576),
+    line(53, "L3", "L3-M4", "La2", 3926.2, 0.0826471),
+    line(53, "L3", "L3-M5", "La1", 3937.7, 0.742757),
+    line(53, "L3", "L3-N1", "Lb6", 4371.0, 0.00488734),
+    line(53, "L3", "L3-N4,5", "Lb2,15", 4506.4, 0.140051),
+    line(53, "M2", "M2-N4", "M2-N4", 880.4, 1.0),
+    line(53, "M3", "M3-N5", "Mg", 826.1, 1.0),
+    line(53, "M4", "M4,5-N2,3", "Mz", 507.8, 1.0),
+    line(54, "K", "K-L1", "Ka3", 29108.0, 0.000518634),
+    line(54, "K", "K-L2", "Ka2", 29454.0, 0.283302),
+    line(54, "K", "K-L3", "Ka1", 29775.0, 0.524827),
+    line(54, "K", "K-M2", "Kb3", 33558.9, 0.0522399),
+    line(54, "K", "K-M3", "Kb1", 33620.4, 0.101299),
+    line(54, "K", "K-M4,5", "Kb5", 33872.0, 0.00133768),
+    line(54, "K", "K-N2,3", "Kb2", 34414.3, 0.0361014),
+    line(54, "K", "K-N4,5", "Kb4", 34491.5, 0.000374097),
+    line(54, "L1", "L1-M2", "Lb4", 4450.9, 0.298526),
+    line(54, "L1", "L1-M3", "Lb3", 4512.4, 0.497996),
+    line(54, "L1", "L1-N2", "Lg2", 5306.3, 0.0881452),
+    line(54, "L1", "L1-N3", "Lg3", 5307.5, 0.115333),
+    line(54, "L2", "L2-M1", "Ln", 3958.3, 0.0249094),
+    line(54, "L2", "L2-M4", "Lb1", 4418.0, 0.85671),
+    line(54, "L2", "L2-N4", "Lg1", 5037.5, 0.118381),
+    line(54, "L3", "L3-M1", "Ll", 3637.3, 0.029475),
+    line(54, "L3", "L3-M4", "La2", 4097.0, 0.0822849),
+    line(54, "L3", "L3-M5", "La1", 4109.6, 0.739409),
+    line(54, "L3", "L3-N1", "Lb6", 4572.8, 0.00509453),
+    line(54, "L3", "L3-N4,5", "Lb2,15", 4716.5, 0.143737),
+    line(54, "M2", "M2-N4", "M2-N4", 932.6, 1.0),
+    line(54, "M3", "M3-N5", "Mg", 873.1, 1.0),
+    line(54, "M4", "M4,5-N2,3", "Mz", 542.3, 1.0),
+    line(55, "K", "K-L1", "Ka3", 30271.0, 0.000526342),
+    line(55, "K", "K-L2", "Ka2", 30626.0, 0.283069),
+    line(55, "K", "K-L3", "Ka1", 30973.0, 0.522942),
+    line(55, "K", "K-M2", "Kb3", 34914.0, 0.0524632),
+    line(55, "K", "K-M3", "Kb1", 34982.0, 0.101702),
+    line(55, "K", "K-M4,5", "Kb5", 35244.5, 0.00135756),
+    line(55, "K", "K-N2,3", "Kb2", 35812.6, 0.0375598),
+    line(55, "K", "K-N4,5", "Kb4", 35905.2, 0.000379656),
+    line(55, "L1", "L1-M2", "Lb4", 4643.0, 0.296686),
+    line(55, "L1", "L1-M3", "Lb3", 4711.0, 0.497532),
+    line(55, "L1", "L1-N2", "Lg2", 5541.6, 0.0888095),
+    line(55, "L1", "L1-N3", "Lg3", 5552.7, 0.116972),
+    line(55, "L2", "L2-M1", "Ln", 4148.0, 0.0241467),
+    line(55, "L2", "L2-M4", "Lb1", 4618.5, 0.854332),
+    line(55, "L2", "L2-N4", "Lg1", 5279.2, 0.121521),
+    line(55, "L3", "L3-M1", "Ll", 3801.0, 0.0293504),
+    line(55, "L3", "L3-M4", "La2", 4271.5, 0.081978),
+    line(55, "L3", "L3-M5", "La1", 4285.4, 0.736557),
+    line(55, "L3", "L3-N1", "Lb6", 4779.7, 0.00530321),
+    line(55, "L3", "L3-N4,5", "Lb2,15", 4932.2, 0.146812),
+    line(55, "M2", "M2-N4", "M2-N4", 991.2, 1.0),
+    line(55, "M3", "M3-N5", "Mg", 925.5, 1.0),
+    line(55, "M4", "M4,5-N2,3", "Mz", 568.1, 1.0),
+    line(56, "K", "K-L1", "Ka3", 31452.0, 0.000533993),
+    line(56, "K", "K-L2", "Ka2", 31817.0, 0.282837),
+    line(56, "K", "K-L3", "Ka1", 32194.0, 0.521071),
+    line(56, "K", "K-M2", "Kb3", 36304.0, 0.052685),
+    line(56, "K", "K-M3", "Kb1", 36378.0, 0.102103),
+    line(56, "K", "K-M4,5", "Kb5", 36645.3, 0.00137729),
+    line(56, "K", "K-N2,3", "Kb2", 37249.0, 0.0390078),
+    line(56, "K", "K-N4,5", "Kb4", 37348.4, 0.000385176),
+    line(56, "L1", "L1-M2", "Lb4", 4852.0, 0.295141),
+    line(56, "L1", "L1-M3", "Lb3", 4926.0, 0.496835),
+    line(56, "L1", "L1-N2", "Lg2", 5797.0, 0.0894302),
+    line(56, "L1", "L1-N3", "Lg3", 5810.4, 0.118594),
+    line(56, "L2", "L2-M1", "Ln", 4331.0, 0.0234397),
+    line(56, "L2", "L2-M4", "Lb1", 4828.3, 0.852142),
+    line(56, "L2", "L2-N4", "Lg1", 5531.4, 0.124418),
+    line(56, "L3", "L3-M1", "Ll", 3954.0, 0.0292812),
+    line(56, "L3", "L3-M4", "La2", 4451.3, 0.0817226),
+    line(56, "L3", "L3-M5", "La1", 4466.5, 0.734168),
+    line(56, "L3", "L3-N1", "Lb6", 4993.5, 0.0055136),
+    line(56, "L3", "L3-N4,5", "Lb2,15", 5154.4, 0.149314),
+    line(56, "M2", "M2-N4", "M2-N4", 1044.4, 1.0),
+    line(56, "M3", "M3-N5", "Mg", 973.1, 1.0),
+    line(56, "M4", "M4,5-N2,3", "Mz", 603.7, 1.0),
+    line(57, "K", "K-L1", "Ka3", 32659.0, 0.000542196),
+    line(57, "K", "K-L2", "Ka2", 33034.0, 0.282962),
+    line(57, "K", "K-L3", "Ka1", 33442.0, 0.519793),
+    line(57, "K", "K-M2", "Kb3", 37716.0, 0.0529529),
+    line(57, "K", "K-M3", "Kb1", 37797.0, 0.102587),
+    line(57, "K", "K-M4,5", "Kb5", 38072.0, 0.00139845),
+    line(57, "K", "K-N2,3", "Kb2", 38719.2, 0.0393731),
+    line(57, "K", "K-N4,5", "Kb4", 38819.7, 0.000391092),
+    line(57, "L1", "L1-M2", "Lb4", 5057.0, 0.293892),
+    line(57, "L1", "L1-M3", "Lb3", 5138.0, 0.495905),
+    line(57, "L1", "L1-N2", "Lg2", 6060.2, 0.0900067),
+    line(57, "L1", "L1-N3", "Lg3", 6070.0, 0.120197),
+    line(57, "L2", "L2-M1", "Ln", 4529.0, 0.022786),
+    line(57, "L2", "L2-M4", "Lb1", 5038.0, 0.850116),
+    line(57, "L2", "L2-N4", "Lg1", 5785.7, 0.127098),
+    line(57, "L3", "L3-M1", "Ll", 4121.0, 0.0292651),
+    line(57, "L3", "L3-M4", "La2", 4630.0, 0.081515),
+    line(57, "L3", "L3-M5", "La1", 4647.0, 0.73221),
+    line(57, "L3", "L3-N1", "Lb6", 5208.3, 0.00572588),
+    line(57, "L3", "L3-N4,5", "Lb2,15", 5377.7, 0.151284),
+    line(57, "M2", "M2-N4", "M2-N4", 1103.7, 1.0),
+    line(57, "M3", "M3-N5", "Mg", 1025.5, 1.0),
+    line(57, "M4", "M4,5-N2,3", "Mz", 647.2, 1.0),
+    line(58, "K", "K-L1", "Ka3", 33894.0, 0.000550358),
+    line(58, "K", "K-L2", "Ka2", 34279.0, 0.283087),
+    line(58, "K", "K-L3", "Ka1", 34720.0, 0.518521),
+    line(58, "K", "K-M2", "Kb3", 39169.0, 0.0532197),
+    line(58, "K", "K-M3", "Kb1", 39256.0, 0.103069),
+    line(58, "K", "K-M4,5", "Kb5", 39540.6, 0.0014195),
+    line(58, "K", "K-N2,3", "Kb2", 40219.8, 0.0397366),
+    line(58, "K", "K-N4,5", "Kb4", 40334.0, 0.00039698),
+    line(58, "L1", "L1-M2", "Lb4", 5275.0, 0.292939),
+    line(58, "L1", "L1-M3", "Lb3", 5362.0, 0.494745),
+    line(58, "L1", "L1-N2", "Lg2", 6325.8, 0.0905383),
+    line(58, "L1", "L1-N3", "Lg3", 6342.5, 0.121778),
+    line(58, "L2", "L2-M1", "Ln", 4728.0, 0.0221835),
+    line(58, "L2", "L2-M4", "Lb1", 5261.6, 0.848236),
+    line(58, "L2", "L2-N4", "Lg1", 6055.0, 0.12958),
+    line(58, "L3", "L3-M1", "Ll", 4287.0, 0.0292999),
+    line(58, "L3", "L3-M4", "La2", 4820.6, 0.081352),
+    line(58, "L3", "L3-M5", "La1", 4839.2, 0.730653),
+    line(58, "L3", "L3-N1", "Lb6", 5432.0, 0.00594021),
+    line(58, "L3", "L3-N4,5", "Lb2,15", 5614.0, 0.152755),
+    line(58, "M2", "M2-N4", "M2-N4", 1165.0, 1.0),
+    line(58, "M3", "M3-N5", "Mg", 1078.0, 1.0),
+    line(58, "M4", "M4,5-N2,3", "Mz", 679.2, 0.00293255),
+    line(58, "M4", "M4-N6", "Mb", 902.3, 0.997068),
+    line(58, "M5", "M5-N6,7", "Ma", 883.7, 1.0),
+    line(59, "K", "K-L1", "Ka3", 35156.0, 0.000558481),
+    line(59, "K", "K-L2", "Ka2", 35551.0, 0.28321),
+    line(59, "K", "K-L3", "Ka1", 36027.0, 0.517256),
+    line(59, "K", "K-M2", "Kb3", 40654.0, 0.0534853),
+    line(59, "K", "K-M3", "Kb1", 40749.0, 0.103548),
+    line(59, "K", "K-M4,5", "Kb5", 41042.7, 0.00144045),
+    line(59, "K", "K-N2,3", "Kb2", 41754.7, 0.0400983),
+    line(59, "K", "K-N4,5", "Kb4", 41875.9, 0.000402839),
+    line(59, "L1", "L1-M2", "Lb4", 5498.0, 0.292282),
+    line(59, "L1", "L1-M3", "Lb3", 5593.0, 0.493358),
+    line(59, "L1", "L1-N2", "Lg2", 6598.7, 0.0910245),
+    line(59, "L1", "L1-N3", "Lg3", 6617.4, 0.123335),
+    line(59, "L2", "L2-M1", "Ln", 4929.0, 0.0216299),
+    line(59, "L2", "L2-M4", "Lb1", 5491.7, 0.846481),
+    line(59, "L2", "L2-N4", "Lg1", 6324.9, 0.131889),
+    line(59, "L3", "L3-M1", "Ll", 4453.0, 0.0293836),
+    line(59, "L3", "L3-M4", "La2", 5015.7, 0.0812304),
+    line(59, "L3", "L3-M5", "La1", 5035.2, 0.729468),
+    line(59, "L3", "L3-N1", "Lb6", 5659.5, 0.00615671),
+    line(59, "L3", "L3-N4,5", "Lb2,15", 5848.9, 0.153761),
+    line(59, "M2", "M2-N4", "M2-N4", 1221.9, 1.0),
+    line(59, "M3", "M3-N5", "Mg", 1126.9, 1.0),
+    line(59, "M4", "M4,5-N2,3", "Mz", 712.0, 0.00293255),
+    line(59, "M4", "M4-N6", "Mb", 946.3, 0.997068),
+    line(59, "M5", "M5-N6,7", "Ma", 926.8, 1.0),
+    line(60, "K", "K-L1", "Ka3", 36443.0, 5.86042e-05),
+    line(60, "K", "K-L2", "Ka2", 36847.0, 0.283478),
+    line(60, "K", "K-L3", "Ka1", 37361.0, 0.516259),
+    line(60, "K", "K-M2", "Kb3", 42166.0, 0.0537772),
+    line(60, "K", "K-M3", "Kb1", 42272.0, 0.104078),
+    line(60, "K", "K-M4,5", "Kb5", 42565.7, 0.00146204),
+    line(60, "K", "K-N2,3", "Kb2", 43325.7, 0.0404788),
+    line(60, "K", "K-N4,5", "Kb4", 43448.5, 0.000408877),
+    line(60, "L1", "L1-M2", "Lb4", 5723.0, 0.291921),
+    line(60, "L1", "L1-M3", "Lb3", 5829.0, 0.491747),
+    line(60, "L1", "L1-N2", "Lg2", 6882.7, 0.0914649),
+    line(60, "L1", "L1-N3", "Lg3", 6901.4, 0.124867),
+    line(60, "L2", "L2-M1", "Ln", 5147.0, 0.0211232),
+    line(60, "L2", "L2-M4", "Lb1", 5718.7, 0.844832),
+    line(60, "L2", "L2-N4", "Lg1", 6601.5, 0.134045),
+    line(60, "L3", "L3-M1", "Ll", 4633.0, 0.0295191),
+    line(60, "L3", "L3-M4", "La2", 5204.7, 0.0811602),
+    line(60, "L3", "L3-M5", "La1", 5227.6, 0.728745),
+    line(60, "L3", "L3-N1", "Lb6", 5888.8, 0.00621882),
+    line(60, "L3", "L3-N4,5", "Lb2,15", 6087.5, 0.154357),
+    line(60, "M2", "M2-N4", "M2-N4", 1282.5, 1.0),
+    line(60, "M3", "M3-N5", "Mg", 1176.5, 1.0),
+    line(60, "M4", "M4,5-N2,3", "Mz", 760.0, 0.00293255),
+    line(60, "M4", "M4-N6", "Mb", 1001.8, 0.997068),
+    line(60, "M5", "M5-N6,7", "Ma", 978.9, 1.0),
+    line(61, "K", "K-L1", "Ka3", 37756.0, 6.61864e-05),
+    line(61, "K", "K-L2", "Ka2", 38171.0, 0.283704),
+    line(61, "K", "K-L3", "Ka1", 38725.0, 0.515076),
+    line(61, "K", "K-M2", "Kb3", 43713.0, 0.0540094),
+    line(61, "K", "K-M3", "Kb1", 43827.0, 0.104507),
+    line(61, "K", "K-M4,5", "Kb5", 44132.0, 0.00148301),
+    line(61, "K", "K-N2,3", "Kb2", 44942.0, 0.0407398),
+    line(61, "K", "K-N4,5", "Kb4", 45064.0, 0.000414739),
+    line(61, "L1", "L1-M2", "Lb4", 5957.0, 0.291853),
+    line(61, "L1", "L1-M3", "Lb3", 6071.0, 0.489917),
+    line(61, "L1", "L1-N2", "Lg2", 7186.0, 0.0918594),
+    line(61, "L1", "L1-N3", "Lg3", 7186.0, 0.126371),
+    line(61, "L2", "L2-M1", "Ln", 5358.0, 0.0206615),
+    line(61, "L2", "L2-M4", "Lb1", 5961.0, 0.843272),
+    line(61, "L2", "L2-N4", "Lg1", 6893.0, 0.136067),
+    line(61, "L3", "L3-M1", "Ll", 4804.0, 0.0296953),
+    line(61, "L3", "L3-M4", "La2", 5407.0, 0.0811122),
+    line(61, "L3", "L3-M5", "La1", 5432.0, 0.728221),
+    line(61, "L3", "L3-N1", "Lb6", 6126.3, 0.00644786),
+    line(61, "L3", "L3-N4,5", "Lb2,15", 6339.0, 0.154523),
+    line(61, "M2", "M2-N4", "M2-N4", 1351.0, 1.0),
+    line(61, "M3", "M3-N5", "Mg", 1237.0, 1.0),
+    line(61, "M4", "M4,5-N2,3", "Mz", 810.0, 0.00293255),
+    line(61, "M4", "M4-N6", "Mb", 1050.5, 0.997068),
+    line(61, "M5", "M5-N6,7", "Ma", 1025.5, 1.0),
+    line(62, "K", "K-L1", "Ka3", 39097.0, 7.36865e-05),
+    line(62, "K", "K-L2", "Ka2", 39522.0, 0.283929),
+    line(62, "K", "K-L3", "Ka1", 40118.0, 0.513898),
+    line(62, "K", "K-M2", "Kb3", 45293.0, 0.0542406),
+    line(62, "K", "K-M3", "Kb1", 45414.0, 0.104934),
+    line(62, "K", "K-M4,5", "Kb5", 45723.1, 0.00150387),
+    line(62, "K", "K-N2,3", "Kb2", 46568.4, 0.0409996),
+    line(62, "K", "K-N4,5", "Kb4", 46705.0, 0.000420574),
+    line(62, "L1", "L1-M2", "Lb4", 6196.0, 0.292076),
+    line(62, "L1", "L1-M3", "Lb3", 6317.0, 0.487871),
+    line(62, "L1", "L1-N2", "Lg2", 7471.4, 0.0922077),
+    line(62, "L1", "L1-N3", "Lg3", 7489.6, 0.127845),
+    line(62, "L2", "L2-M1", "Ln", 5589.0, 0.020243),
+    line(62, "L2", "L2-M4", "Lb1", 6201.1, 0.841785),
+    line(62, "L2", "L2-N4", "Lg1", 7183.0, 0.137972),
+    line(62, "L3", "L3-M1", "Ll", 4993.0, 0.0299157),
+    line(62, "L3", "L3-M4", "La2", 5605.1, 0.0810972),
+    line(62, "L3", "L3-M5", "La1", 5632.6, 0.727994),
+    line(62, "L3", "L3-N1", "Lb6", 6368.8, 0.00667929),
+    line(62, "L3", "L3-N4,5", "Lb2,15", 6587.0, 0.154314),
+    line(62, "M2", "M2-N4", "M2-N4", 1412.0, 1.0),
+    line(62, "M3", "M3-N5", "Mg", 1291.0, 1.0),
+    line(62, "M4", "M4,5-N2,3", "Mz", 845.3, 0.00293255),
+    line(62, "M4", "M4-N6", "Mb", 1105.7, 0.997068),
+    line(62, "M5", "M5-N6,7", "Ma", 1078.2, 1.0),
+    line(63, "K", "K-L1", "Ka3", 40467.0, 8.12742e-05),
+    line(63, "K", "K-L2", "Ka2", 40902.0, 0.284153),
+    line(63, "K", "K-L3", "Ka1", 41542.0, 0.512726),
+    line(63, "K", "K-M2", "Kb3", 46905.0, 0.0544709),
+    line(63, "K", "K-M3", "Kb1", 47038.0, 0.105359),
+    line(63, "K", "K-M4,5", "Kb5", 47360.4, 0.00152464),
+    line(63, "K", "K-N2,3", "Kb2", 48235.0, 0.0412583),
+    line(63, "K", "K-N4,5", "Kb4", 48386.0, 0.000426383),
+    line(63, "L1", "L1-M2", "Lb4", 6438.0, 0.292586),
+    line(63, "L1", "L1-M3", "Lb3", 6571.0, 0.485616),
+    line(63, "L1", "L1-N2", "Lg2", 7768.0, 0.0925099),
+    line(63, "L1", "L1-N3", "Lg3", 7795.0, 0.129288),
+    line(63, "L2", "L2-M1", "Ln", 5817.0, 0.019866),
+    line(63, "L2", "L2-M4", "Lb1", 6458.4, 0.840356),
+    line(63, "L2", "L2-N4", "Lg1", 7484.0, 0.139778),
+    line(63, "L3", "L3-M1", "Ll", 5177.0, 0.0301787),
+    line(63, "L3", "L3-M4", "La2", 5818.4, 0.0811125),
+    line(63, "L3", "L3-M5", "La1", 5849.5, 0.728039),
+    line(63, "L3", "L3-N1", "Lb6", 6617.0, 0.00691316),
+    line(63, "L3", "L3-N4,5", "Lb2,15", 6844.0, 0.153756),
+    line(63, "M2", "M2-N4", "M2-N4", 1481.0, 1.0),
+    line(63, "M3", "M3-N5", "Mg", 1353.3, 1.0),
+    line(63, "M4", "M4,5-N2,3", "Mz", 874.6, 0.00293255),
+    line(63, "M4", "M4-N6", "Mb", 1158.6, 0.997068),
+    line(63, "M5", "M5-N6,7", "Ma", 1127.5, 1.0),
+    line(64, "K", "K-L1", "Ka3", 41863.0, 8.90656e-05),
+    line(64, "K", "K-L2", "Ka2", 42309.0, 0.284221),
+    line(64, "K", "K-L3", "Ka1", 42996.0, 0.51128),
+    line(64, "K", "K-M2", "Kb3", 48551.0, 0.0546456),
+    line(64, "K", "K-M3", "Kb1", 48695.0, 0.105677),
+    line(64, "K", "K-M4,5", "Kb5", 49017.1, 0.00154884),
+    line(64, "K", "K-N2,3", "Kb2", 49953.0, 0.0421001),
+    line(64, "K", "K-N4,5", "Kb4", 50096.4, 0.000438411),
+    line(64, "L1", "L1-M2", "Lb4", 6688.0, 0.293244),
+    line(64, "L1", "L1-M3", "Lb3", 6832.0, 0.482936),
+    line(64, "L1", "L1-N2", "Lg2", 8090.0, 0.0931822),
+    line(64, "L1", "L1-N3", "Lg3", 8105.0, 0.130638),
+    line(64, "L2", "L2-M1", "Ln", 6049.0, 0.0195289),
+    line(64, "L2", "L2-M4", "Lb1", 6708.1, 0.838972),
+    line(64, "L2", "L2-N4", "Lg1", 7787.4, 0.141499),
+    line(64, "L3", "L3-M1", "Ll", 5362.0, 0.0304831),
+    line(64, "L3", "L3-M4", "La2", 6021.1, 0.0811557),
+    line(64, "L3", "L3-M5", "La1", 6053.4, 0.728335),
+    line(64, "L3", "L3-N1", "Lb6", 6864.4, 0.00714951),
+    line(64, "L3", "L3-N4,5", "Lb2,15", 7100.4, 0.152877),
+    line(64, "M2", "M2-N4", "M2-N4", 1545.4, 1.0),
+    line(64, "M3", "M3-N5", "Mg", 1401.4, 1.0),
+    line(64, "M4", "M4,5-N2,3", "Mz", 935.9, 0.00293255),
+    line(64, "M4", "M4-N6", "Mb", 1213.3, 0.997068),
+    line(64, "M5", "M5-N6,7", "Ma", 1181.0, 1.0),
+    line(65, "K", "K-L1", "Ka3", 43288.0, 9.73843e-05),
+    line(65, "K", "K-L2", "Ka2", 43744.0, 0.284687),
+    line(65, "K", "K-L3", "Ka1", 44482.0, 0.510467),
+    line(65, "K", "K-M2", "Kb3", 50228.0, 0.0548543),
+    line(65, "K", "K-M3", "Kb1", 50385.0, 0.106081),
+    line(65, "K", "K-M4,5", "Kb5", 50719.1, 0.00164881),
+    line(65, "K", "K-N2,3", "Kb2", 51673.6, 0.0417168),
+    line(65, "K", "K-N4,5", "Kb4", 51845.5, 0.000447722),
+    line(65, "L1", "L1-M2", "Lb4", 6940.0, 0.294412),
+    line(65, "L1", "L1-M3", "Lb3", 7097.0, 0.480439),
+    line(65, "L1", "L1-N2", "Lg2", 8385.6, 0.0930922),
+    line(65, "L1", "L1-N3", "Lg3", 8423.9, 0.132056),
+    line(65, "L2", "L2-M1", "Ln", 6284.0, 0.0192301),
+    line(65, "L2", "L2-M4", "Lb1", 6975.1, 0.837618),
+    line(65, "L2", "L2-N4", "Lg1", 8101.5, 0.143152),
+    line(65, "L3", "L3-M1", "Ll", 5546.0, 0.0308277),
+    line(65, "L3", "L3-M4", "La2", 6237.1, 0.0812242),
+    line(65, "L3", "L3-M5", "La1", 6272.9, 0.728857),
+    line(65, "L3", "L3-N1", "Lb6", 7118.0, 0.00738835),
+    line(65, "L3", "L3-N4,5", "Lb2,15", 7363.5, 0.151703),
+    line(65, "M2", "M2-N4", "M2-N4", 1617.5, 1.0),
+    line(65, "M3", "M3-N5", "Mg", 1460.5, 1.0),
+    line(65, "M4", "M4,5-N2,3", "Mz", 954.5, 0.00293255),
+    line(65, "M4", "M4-N6", "Mb", 1269.2, 0.997068),
+    line(65, "M5", "M5-N6,7", "Ma", 1233.4, 1.0),
+    line(66, "K", "K-L1", "Ka3", 44743.0, 0.000106231),
+    line(66, "K", "K-L2", "Ka2", 45208.0, 0.285029),
+    line(66, "K", "K-L3", "Ka1", 45999.0, 0.509344),
+    line(66, "K", "K-M2", "Kb3", 51947.0, 0.0550148),
+    line(66, "K", "K-M3", "Kb1", 52113.0, 0.106398),
+    line(66, "K", "K-M4,5", "Kb5", 52456.0, 0.00174739),
+    line(66, "K", "K-N2,3", "Kb2", 53455.5, 0.0419012),
+    line(66, "K", "K-N4,5", "Kb4", 53635.4, 0.000459254),
+    line(66, "L1", "L1-M2", "Lb4", 7204.0, 0.295806),
+    line(66, "L1", "L1-M3", "Lb3", 7370.0, 0.477673),
+    line(66, "L1", "L1-N2", "Lg2", 8712.5, 0.0931021),
+    line(66, "L1", "L1-N3", "Lg3", 8752.8, 0.133418),
+    line(66, "L2", "L2-M1", "Ln", 6534.0, 0.018968),
+    line(66, "L2", "L2-M4", "Lb1", 7248.0, 0.836285),
+    line(66, "L2", "L2-N4", "Lg1", 8427.4, 0.144747),
+    line(66, "L3", "L3-M1", "Ll", 5743.0, 0.0312112),
+    line(66, "L3", "L3-M4", "La2", 6457.0, 0.0813156),
+    line(66, "L3", "L3-M5", "La1", 6497.4, 0.729584),
+    line(66, "L3", "L3-N1", "Lb6", 7375.8, 0.00762967),
+    line(66, "L3", "L3-N4,5", "Lb2,15", 7636.4, 0.15026),
+    line(66, "M2", "M2-N4", "M2-N4", 1688.4, 1.0),
+    line(66, "M3", "M3-N5", "Mg", 1522.4, 1.0),
+    line(66, "M4", "M4,5-N2,3", "Mz", 999.5, 0.00293255),
+    line(66, "M4", "M4-N6", "Mb", 1325.0, 0.997068),
+    line(66, "M5", "M5-N6,7", "Ma", 1284.6, 1.0),
+    line(67, "K", "K-L1", "Ka3", 46224.0, 0.000115822),
+    line(67, "K", "K-L2", "Ka2", 46700.0, 0.285369),
+    line(67, "K", "K-L3", "Ka1", 47547.0, 0.508226),
+    line(67, "K", "K-M2", "Kb3", 53695.0, 0.0551745),
+    line(67, "K", "K-M3", "Kb1", 53877.0, 0.106714),
+    line(67, "K", "K-M4,5", "Kb5", 54226.0, 0.00184553),
+    line(67, "K", "K-N2,3", "Kb2", 55274.5, 0.0420822),
+    line(67, "K", "K-N4,5", "Kb4", 55458.0, 0.000473287),
+    line(67, "L1", "L1-M2", "Lb4", 7471.0, 0.297416),
+    line(67, "L1", "L1-M3", "Lb3", 7653.0, 0.47464),
+    line(67, "L1", "L1-N2", "Lg2", 9050.5, 0.0932251),
+    line(67, "L1", "L1-N3", "Lg3", 9085.8, 0.134719),
+    line(67, "L2", "L2-M1", "Ln", 6790.0, 0.0187411),
+    line(67, "L2", "L2-M4", "Lb1", 7526.0, 0.834959),
+    line(67, "L2", "L2-N4", "Lg1", 8758.0, 0.1463),
+    line(67, "L3", "L3-M1", "Ll", 5943.0, 0.0316326),
+    line(67, "L3", "L3-M4", "La2", 6679.0, 0.0814273),
+    line(67, "L3", "L3-M5", "La1", 6720.0, 0.730494),
+    line(67, "L3", "L3-N1", "Lb6", 7638.6, 0.00787342),
+    line(67, "L3", "L3-N4,5", "Lb2,15", 7911.0, 0.148573),
+    line(67, "M2", "M2-N4", "M2-N4", 1763.0, 1.0),
+    line(67, "M3", "M3-N5", "Mg", 1581.0, 1.0),
+    line(67, "M4", "M4,5-N2,3", "Mz", 1048.5, 0.00293255),
+    line(67, "M4", "M4-N6", "Mb", 1383.4, 0.997068),
+    line(67, "M5", "M5-N6,7", "Ma", 1342.4, 1.0),
+    line(68, "K", "K-L1", "Ka3", 47735.0, 0.000126316),
+    line(68, "K", "K-L2", "Ka2", 48222.0, 0.285707),
+    line(68, "K", "K-L3", "Ka1", 49128.0, 0.507113),
+    line(68, "K", "K-M2", "Kb3", 55480.0, 0.0553334),
+    line(68, "K", "K-M3", "Kb1", 55674.0, 0.107028),
+    line(68, "K", "K-M4,5", "Kb5", 56033.0, 0.00194324),
+    line(68, "K", "K-N2,3", "Kb2", 57119.8, 0.0422597),
+    line(68, "K", "K-N4,5", "Kb4", 57318.4, 0.000489835),
+    line(68, "L1", "L1-M2", "Lb4", 7745.0, 0.299229),
+    line(68, "L1", "L1-M3", "Lb3", 7939.0, 0.471341),
+    line(68, "L1", "L1-N2", "Lg2", 9384.8, 0.0934733),
+    line(68, "L1", "L1-N3", "Lg3", 9430.8, 0.135957),
+    line(68, "L2", "L2-M1", "Ln", 7057.0, 0.0185481),
+    line(68, "L2", "L2-M4", "Lb1", 7811.0, 0.833631),
+    line(68, "L2", "L2-N4", "Lg1", 9096.4, 0.147821),
+    line(68, "L3", "L3-M1", "Ll", 6151.0, 0.0320908),
+    line(68, "L3", "L3-M4", "La2", 6905.0, 0.0815571),
+    line(68, "L3", "L3-M5", "La1", 6949.0, 0.731565),
+    line(68, "L3", "L3-N1", "Lb6", 7908.2, 0.00811955),
+    line(68, "L3", "L3-N4,5", "Lb2,15", 8190.4, 0.146668),
+    line(68, "M2", "M2-N4", "M2-N4", 1838.4, 1.0),
+    line(68, "M3", "M3-N5", "Mg", 1644.4, 1.0),
+    line(68, "M4", "M4,5-N2,3", "Mz", 1086.8, 0.00293255),
+    line(68, "M4", "M4-N6", "Mb", 1445.0, 0.997068),
+    line(68, "M5", "M5-N6,7", "Ma", 1401.0, 1.0),
+    line(69, "K", "K-L1", "Ka3", 49274.0, 0.000137883),
+    line(69, "K", "K-L2", "Ka2", 49773.0, 0.286091),
+    line(69, "K", "K-L3", "Ka1", 50742.0, 0.506042),
+    line(69, "K", "K-M2", "Kb3", 57300.0, 0.0554928),
+    line(69, "K", "K-M3", "Kb1", 57505.0, 0.107284),
+    line(69, "K", "K-M4,5", "Kb5", 57875.0, 0.00204068),
+    line(69, "K", "K-N2,3", "Kb2", 59004.1, 0.0424034),
+    line(69, "K", "K-N4,5", "Kb4", 59214.5, 0.000508947),
+    line(69, "L1", "L1-M2", "Lb4", 8026.0, 0.301233),
+    line(69, "L1", "L1-M3", "Lb3", 8231.0, 0.467781),
+    line(69, "L1", "L1-N2", "Lg2", 9730.1, 0.0938586),
+    line(69, "L1", "L1-N3", "Lg3", 9783.4, 0.137127),
+    line(69, "L2", "L2-M1", "Ln", 7310.0, 0.0183876),
+    line(69, "L2", "L2-M4", "Lb1", 8102.0, 0.832292),
+    line(69, "L2", "L2-N4", "Lg1", 9441.5, 0.14932),
+    line(69, "L3", "L3-M1", "Ll", 6341.0, 0.0325845),
+    line(69, "L3", "L3-M4", "La2", 7133.0, 0.0817023),
+    line(69, "L3", "L3-M5", "La1", 7180.0, 0.732774),
+    line(69, "L3", "L3-N1", "Lb6", 8177.1, 0.00836795),
+    line(69, "L3", "L3-N4,5", "Lb2,15", 8472.5, 0.144571),
+    line(69, "M2", "M2-N4", "M2-N4", 1914.5, 1.0),
+    line(69, "M3", "M3-N5", "Mg", 1709.5, 1.0),
+    line(69, "M4", "M4,5-N2,3", "Mz", 1129.1, 0.00293255),
+    line(69, "M4", "M4-N6", "Mb", 1507.0, 0.997068),
+    line(69, "M5", "M5-N6,7", "Ma", 1460.0, 1.0),
+    line(70, "K", "K-L1", "Ka3", 50846.0, 0.000150676),
+    line(70, "K", "K-L2", "Ka2", 51354.0, 0.286472),
+    line(70, "K", "K-L3", "Ka1", 52388.0, 0.504974),
+    line(70, "K", "K-M2", "Kb3", 59159.0, 0.0556516),
+    line(70, "K", "K-M3", "Kb1", 59382.0, 0.107539),
+    line(70, "K", "K-M4,5", "Kb5", 59756.0, 0.00213771),
+    line(70, "K", "K-N2,3", "Kb2", 60943.3, 0.0425437),
+    line(70, "K", "K-N4,5", "Kb4", 61140.8, 0.000530599),
+    line(70, "L1", "L1-M2", "Lb4", 8313.0, 0.303417),
+    line(70, "L1", "L1-M3", "Lb3", 8536.0, 0.463965),
+    line(70, "L1", "L1-N2", "Lg2", 10097.3, 0.0943912),
+    line(70, "L1", "L1-N3", "Lg3", 10146.3, 0.138227),
+    line(70, "L2", "L2-M1", "Ln", 7580.0, 0.018258),
+    line(70, "L2", "L2-M4", "Lb1", 8402.0, 0.830934),
+    line(70, "L2", "L2-N4", "Lg1", 9786.8, 0.150808),
+    line(70, "L3", "L3-M1", "Ll", 6546.0, 0.033097),
+    line(70, "L3", "L3-M4", "La2", 7368.0, 0.0818215),
+    line(70, "L3", "L3-M5", "La1", 7416.0, 0.73375),
+    line(70, "L3", "L3-N1", "Lb6", 8463.5, 0.00861437),
+    line(70, "L3", "L3-N4,5", "Lb2,15", 8752.8, 0.142717),
+    line(70, "M2", "M2-N4", "M2-N4", 1981.8, 1.0),
+    line(70, "M3", "M3-N5", "Mg", 1767.6, 1.0),
+    line(70, "M4", "M4,5-N2,3", "Mz", 1187.3, 0.00293255),
+    line(70, "M4", "M4-N6", "Mb", 1573.5, 0.997068),
+    line(70, "M5", "M5-N6,7", "Ma", 1525.5, 1.0),
+    line(71, "K", "K-L1", "Ka3", 52444.0, 0.00016476),
+    line(71, "K", "K-L2", "Ka2", 52965.0, 0.28675),
+    line(71, "K", "K-L3", "Ka1", 54070.0, 0.503645),
+    line(71, "K", "K-M2", "Kb3", 61050.0, 0.0557575),
+    line(71, "K", "K-M3", "Kb1", 61290.0, 0.107734),
+    line(71, "K", "K-M4,5", "Kb5", 61675.0, 0.00223314),
+    line(71, "K", "K-N2,3", "Kb2", 62901.6, 0.0431618),
+    line(71, "K", "K-N4,5", "Kb4", 63107.9, 0.000554509),
+    line(71, "L1", "L1-M2", "Lb4", 8606.0, 0.305766),
+    line(71, "L1", "L1-M3", "Lb3", 8846.0, 0.459899),
+    line(71, "L1", "L1-N2", "Lg2", 10457.6, 0.0950812),
+    line(71, "L1", "L1-N3", "Lg3", 10510.8, 0.139253),
+    line(71, "L2", "L2-M1", "Ln", 7858.0, 0.0181582),
+    line(71, "L2", "L2-M4", "Lb1", 8710.0, 0.829549),
+    line(71, "L2", "L2-N4", "Lg1", 10142.9, 0.152293),
+    line(71, "L3", "L3-M1", "Ll", 6753.0, 0.0333248),
+    line(71, "L3", "L3-M4", "La2", 7605.0, 0.0811777),
+    line(71, "L3", "L3-M5", "La1", 7655.0, 0.727884),
+    line(71, "L3", "L3-N1", "Lb6", 8737.2, 0.00877891),
+    line(71, "L3", "L3-N4,5", "Lb2,15", 9037.9, 0.148834),
+    line(71, "M2", "M2-N4", "M2-N4", 2057.9, 1.0),
+    line(71, "M3", "M3-N5", "Mg", 1827.7, 1.0),
+    line(71, "M4", "M4,5-N2,3", "Mz", 1226.6, 0.00293255),
+    line(71, "M4", "M4-N6", "Mb", 1630.1, 0.997068),
+    line(71, "M5", "M5-N6,7", "Ma", 1580.1, 1.0),
+    line(72, "K", "K-L1", "Ka3", 54080.0, 0.000180364),
+    line(72, "K", "K-L2", "Ka2", 54612.0, 0.287026),
+    line(72, "K", "K-L3", "Ka1", 55790.0, 0.502321),
+    line(72, "K", "K-M2", "Kb3", 62986.0, 0.0558628),
+    line(72, "K", "K-M3", "Kb1", 63243.0, 0.107927),
+    line(72, "K", "K-M4,5", "Kb5", 63635.0, 0.00232807),
+    line(72, "K", "K-N2,3", "Kb2", 64912.8, 0.043774),
+    line(72, "K", "K-N4,5", "Kb4", 65131.0, 0.000580957),
+    line(72, "L1", "L1-M2", "Lb4", 8906.0, 0.308268),
+    line(72, "L1", "L1-M3", "Lb3", 9163.0, 0.45559),
+    line(72, "L1", "L1-N2", "Lg2", 10832.8, 0.0959371),
+    line(72, "L1", "L1-N3", "Lg3", 10890.3, 0.140204),
+    line(72, "L2", "L2-M1", "Ln", 8138.0, 0.0180868),
+    line(72, "L2", "L2-M4", "Lb1", 9023.0, 0.828129),
+    line(72, "L2", "L2-N4", "Lg1", 10519.0, 0.153784),
+    line(72, "L3", "L3-M1", "Ll", 6960.0, 0.0335997),
+    line(72, "L3", "L3-M4", "La2", 7845.0, 0.0806024),
+    line(72, "L3", "L3-M5", "La1", 7899.0, 0.722634),
+    line(72, "L3", "L3-N1", "Lb6", 9023.0, 0.00894731),
+    line(72, "L3", "L3-N4,5", "Lb2,15", 9341.0, 0.153819),
+    line(72, "L3", "L3-O4,5", "Lb5", 9561.0, 0.000397051),
+    line(72, "M2", "M2-N4", "M2-N4", 2145.0, 1.0),
+    line(72, "M3", "M3-N5", "Mg", 1896.5, 1.0),
+    line(72, "M4", "M4,5-N2,3", "Mz", 1277.8, 0.00293255),
+    line(72, "M4", "M4-N6", "Mb", 1700.1, 0.997068),
+    line(72, "M5", "M5-N6,7", "Ma", 1646.1, 1.0),
+    line(73, "K", "K-L1", "Ka3", 55734.0, 0.00019763),
+    line(73, "K", "K-L2", "Ka2", 56280.0, 0.287361),
+    line(73, "K", "K-L3", "Ka1", 57535.0, 0.500978),
+    line(73, "K", "K-M2", "Kb3", 64947.0, 0.0559526),
+    line(73, "K", "K-M3", "Kb1", 65222.0, 0.1081),
+    line(73, "K", "K-M4,5", "Kb5", 65623.0, 0.00242237),
+    line(73, "K", "K-N2,3", "Kb2", 66952.6, 0.0443779),
+    line(73, "K", "K-N4,5", "Kb4", 67178.1, 0.000609919),
+    line(73, "L1", "L1-M2", "Lb4", 9213.0, 0.310909),
+    line(73, "L1", "L1-M3", "Lb3", 9488.0, 0.451047),
+    line(73, "L1", "L1-N2", "Lg2", 11218.6, 0.0969665),
+    line(73, "L1", "L1-N3", "Lg3", 11281.1, 0.141077),
+    line(73, "L2", "L2-M1", "Ln", 8428.0, 0.0180424),
+    line(73, "L2", "L2-M4", "Lb1", 9343.0, 0.826671),
+    line(73, "L2", "L2-N4", "Lg1", 10898.1, 0.155287),
+    line(73, "L3", "L3-M1", "Ll", 7173.0, 0.033858),
+    line(73, "L3", "L3-M4", "La2", 8088.0, 0.0799445),
+    line(73, "L3", "L3-M5", "La1", 8146.0, 0.716645),
+    line(73, "L3", "L3-N1", "Lb6", 9317.6, 0.00910295),
+    line(73, "L3", "L3-N4,5", "Lb2,15", 9643.1, 0.157578),
+    line(73, "L3", "L3-O4,5", "Lb5", 9881.0, 0.00287193),
+    line(73, "M2", "M2-N4", "M2-N4", 2231.1, 1.0),
+    line(73, "M3", "M3-N5", "Mg", 1967.6, 1.0),
+    line(73, "M4", "M4,5-N2,3", "Mz", 1329.6, 0.00293255),
+    line(73, "M4", "M4-N6", "Mb", 1769.5, 0.997068),
+    line(73, "M5", "M5-N6,7", "Ma", 1711.5, 1.0),
+    line(74, "K", "K-L1", "Ka3", 57425.0, 0.000216736),
+    line(74, "K", "K-L2", "Ka2", 57981.0, 0.287673),
+    line(74, "K", "K-L3", "Ka1", 59318.0, 0.499692),
+    line(74, "K", "K-M2", "Kb3", 66950.0, 0.0560304),
+    line(74, "K", "K-M3", "Kb1", 67244.0, 0.10825),
+    line(74, "K", "K-M4,5", "Kb5", 67653.0, 0.00251642),
+    line(74, "K", "K-N2,3", "Kb2", 69034.6, 0.0449804),
+    line(74, "K", "K-N4,5", "Kb4", 69269.1, 0.000641496),
+    line(74, "L1", "L1-M2", "Lb4", 9525.0, 0.313674),
+    line(74, "L1", "L1-M3", "Lb3", 9819.0, 0.446279),
+    line(74, "L1", "L1-N2", "Lg2", 11609.6, 0.0981763),
+    line(74, "L1", "L1-N3", "Lg3", 11676.4, 0.141871),
+    line(74, "L2", "L2-M1", "Ln", 8724.0, 0.0179174),
+    line(74, "L2", "L2-M4", "Lb1", 9672.0, 0.820295),
+    line(74, "L2", "L2-N4", "Lg1", 11288.1, 0.155881),
+    line(74, "L2", "L2-O4", "Lg6", 11498.7, 0.00590719),
+    line(74, "L3", "L3-M1", "Ll", 7387.0, 0.0341666),
+    line(74, "L3", "L3-M4", "La2", 8335.0, 0.0793658),
+    line(74, "L3", "L3-M5", "La1", 8398.0, 0.711366),
+    line(74, "L3", "L3-N1", "Lb6", 9612.9, 0.00926401),
+    line(74, "L3", "L3-N4,5", "Lb2,15", 9951.1, 0.160579),
+    line(74, "L3", "L3-O4,5", "Lb5", 10207.0, 0.0052579),
+    line(74, "M2", "M2-N4", "M2-N4", 2319.1, 1.0),
+    line(74, "M3", "M3-N5", "Mg", 2037.5, 1.0),
+    line(74, "M4", "M4,5-N2,3", "Mz", 1381.6, 0.00293255),
+    line(74, "M4", "M4-N6", "Mb", 1838.4, 0.997068),
+    line(74, "M5", "M5-N6,7", "Ma", 1775.4, 1.0),
+    line(75, "K", "K-L1", "Ka3", 59149.0, 0.000237785),
+    line(75, "K", "K-L2", "Ka2", 59717.0, 0.288062),
+    line(75, "K", "K-L3", "Ka1", 61141.0, 0.498355),
+    line(75, "K", "K-M2", "Kb3", 68994.0, 0.0561002),
+    line(75, "K", "K-M3", "Kb1", 69309.0, 0.108401),
+    line(75, "K", "K-M4,5", "Kb5", 69727.0, 0.00260968),
+    line(75, "K", "K-N2,3", "Kb2", 71157.3, 0.0455593),
+    line(75, "K", "K-N4,5", "Kb4", 71402.1, 0.00067556),
+    line(75, "L1", "L1-M2", "Lb4", 9845.0, 0.31655),
+    line(75, "L1", "L1-M3", "Lb3", 10160.0, 0.441295),
+    line(75, "L1", "L1-N2", "Lg2", 12008.3, 0.0995718),
+    line(75, "L1", "L1-N3", "Lg3", 12080.2, 0.142584),
+    line(75, "L2", "L2-M1", "Ln", 9027.0, 0.0178552),
+    line(75, "L2", "L2-M4", "Lb1", 10010.0, 0.815642),
+    line(75, "L2", "L2-N4", "Lg1", 11685.1, 0.156817),
+    line(75, "L2", "L2-O4", "Lg6", 11913.4, 0.00968575),
+    line(75, "L3", "L3-M1", "Ll", 7603.0, 0.0345212),
+    line(75, "L3", "L3-M4", "La2", 8586.0, 0.0788546),
+    line(75, "L3", "L3-M5", "La1", 8652.0, 0.706695),
+    line(75, "L3", "L3-N1", "Lb6", 9909.6, 0.00942978),
+    line(75, "L3", "L3-N4,5", "Lb2,15", 10261.1, 0.162937),
+    line(75, "L3", "L3-O4,5", "Lb5", 10535.0, 0.00756208),
+    line(75, "M2", "M2-N4", "M2-N4", 2408.1, 1.0),
+    line(75, "M3", "M3-N5", "Mg", 2106.5, 1.0),
+    line(75, "M4", "M4,5-N2,3", "Mz", 1430.3, 0.00293255),
+    line(75, "M4", "M4-N6", "Mb", 1906.1, 0.997068),
+    line(75, "M5", "M5-N6,7", "Ma", 1840.1, 1.0),
+    line(76, "K", "K-L1", "Ka3", 60903.0, 0.000260945),
+    line(76, "K", "K-L2", "Ka2", 61486.0, 0.288448),
+    line(76, "K", "K-L3", "Ka1", 63000.0, 0.497024),
+    line(76, "K", "K-M2", "Kb3", 71079.0, 0.0561695),
+    line(76, "K", "K-M3", "Kb1", 71414.0, 0.108551),
+    line(76, "K", "K-M4,5", "Kb5", 71840.0, 0.00270245),
+    line(76, "K", "K-N2,3", "Kb2", 73321.9, 0.0461323),
+    line(76, "K", "K-N4,5", "Kb4", 73577.9, 0.000712188),
+    line(76, "L1", "L1-M2", "Lb4", 10176.0, 0.319522),
+    line(76, "L1", "L1-M3", "Lb3", 10511.0, 0.436106),
+    line(76, "L1", "L1-N2", "Lg2", 12418.9, 0.101158),
+    line(76, "L1", "L1-N3", "Lg3", 12497.3, 0.143214),
+    line(76, "L2", "L2-M1", "Ln", 9336.0, 0.0178209),
+    line(76, "L2", "L2-M4", "Lb1", 10354.0, 0.811179),
+    line(76, "L2", "L2-N4", "Lg1", 12091.9, 0.157814),
+    line(76, "L2", "L2-O4", "Lg6", 12327.0, 0.0131864),
+    line(76, "L3", "L3-M1", "Ll", 7822.0, 0.0349165),
+    line(76, "L3", "L3-M4", "La2", 8840.0, 0.0783975),
+    line(76, "L3", "L3-M5", "La1", 8911.0, 0.70251),
+    line(76, "L3", "L3-N1", "Lb6", 10212.8, 0.00959921),
+    line(76, "L3", "L3-N4,5", "Lb2,15", 10577.9, 0.164787),
+    line(76, "L3", "L3-O4,5", "Lb5", 10871.0, 0.00979),
+    line(76, "M2", "M2-N4", "M2-N4", 2498.9, 1.0),
+    line(76, "M3", "M3-N5", "Mg", 2178.5, 1.0),
+    line(76, "M4", "M4,5-N2,3", "Mz", 1481.9, 0.00293255),
+    line(76, "M4", "M4-N6", "Mb", 1977.6, 0.997068),
+    line(76, "M5", "M5-N6,7", "Ma", 1906.6, 1.0),
+    line(77, "K", "K-L1", "Ka3", 62692.0, 0.000286364),
+    line(77, "K", "K-L2", "Ka2", 63287.0, 0.288832),
+    line(77, "K", "K-L3", "Ka1", 64896.0, 0.495699),
+    line(77, "K", "K-M2", "Kb3", 73202.0, 0.0562383),
+    line(77, "K", "K-M3", "Kb1", 73560.0, 0.108699),
+    line(77, "K", "K-M4,5", "Kb5", 73995.0, 0.00279471),
+    line(77, "K", "K-N2,3", "Kb2", 75533.2, 0.0466994),
+    line(77, "K", "K-N4,5", "Kb4", 75799.1, 0.000751386),
+    line(77, "L1", "L1-M2", "Lb4", 10510.0, 0.322577),
+    line(77, "L1", "L1-M3", "Lb3", 10868.0, 0.430725),
+    line(77, "L1", "L1-N2", "Lg2", 12841.2, 0.102936),
+    line(77, "L1", "L1-N3", "Lg3", 12923.2, 0.143762),
+    line(77, "L2", "L2-M1", "Ln", 9650.0, 0.0178129),
+    line(77, "L2", "L2-M4", "Lb1", 10708.0, 0.806893),
+    line(77, "L2", "L2-N4", "Lg1", 12512.1, 0.158874),
+    line(77, "L2", "L2-O4", "Lg6", 12761.0, 0.01642),
+    line(77, "L3", "L3-M1", "Ll", 8041.0, 0.0353488),
+    line(77, "L3", "L3-M4", "La2", 9099.0, 0.0779859),
+    line(77, "L3", "L3-M5", "La1", 9175.0, 0.698733),
+    line(77, "L3", "L3-N1", "Lb6", 10523.9, 0.00977165),
+    line(77, "L3", "L3-N4,5", "Lb2,15", 10903.1, 0.166215),
+    line(77, "L3", "L3-O4,5", "Lb5", 11215.0, 0.0119459),
+    line(77, "M2", "M2-N4", "M2-N4", 2597.1, 1.0),
+    line(77, "M3", "M3-N5", "Mg", 2254.7, 1.0),
+    line(77, "M4", "M4,5-N2,3", "Mz", 1538.2, 0.00293255),
+    line(77, "M4", "M4-N6", "Mb", 2052.2, 0.997068),
+    line(77, "M5", "M5-N6,7", "Ma", 1976.2, 1.0),
+    line(78, "K", "K-L1", "Ka3", 64515.0, 0.000314185),
+    line(78, "K", "K-L2", "Ka2", 65122.0, 0.289212),
+    line(78, "K", "K-L3", "Ka1", 66831.0, 0.49438),
+    line(78, "K", "K-M2", "Kb3", 75368.0, 0.0563065),
+    line(78, "K", "K-M3", "Kb1", 75750.0, 0.108847),
+    line(78, "K", "K-M4,5", "Kb5", 76193.0, 0.00288648),
+    line(78, "K", "K-N2,3", "Kb2", 77785.9, 0.0472606),
+    line(78, "K", "K-N4,5", "Kb4", 78063.4, 0.000793161),
+    line(78, "L1", "L1-M2", "Lb4", 10853.0, 0.3257),
+    line(78, "L1", "L1-M3", "Lb3", 11235.0, 0.425163),
+    line(78, "L1", "L1-N2", "Lg2", 13270.9, 0.104911),
+    line(78, "L1", "L1-N3", "Lg3", 13360.6, 0.144227),
+    line(78, "L2", "L2-M1", "Ln", 9977.0, 0.0178298),
+    line(78, "L2", "L2-M4", "Lb1", 11071.0, 0.802776),
+    line(78, "L2", "L2-N4", "Lg1", 12941.4, 0.159997),
+    line(78, "L2", "L2-O4", "Lg6", 13207.7, 0.0193972),
+    line(78, "L3", "L3-M1", "Ll", 8268.0, 0.0358146),
+    line(78, "L3", "L3-M4", "La2", 9362.0, 0.0776107),
+    line(78, "L3", "L3-M5", "La1", 9442.0, 0.695283),
+    line(78, "L3", "L3-N1", "Lb6", 10838.6, 0.00994635),
+    line(78, "L3", "L3-N4,5", "Lb2,15", 11232.4, 0.167313),
+    line(78, "L3", "L3-O4,5", "Lb5", 11564.0, 0.0140329),
+    line(78, "M2", "M2-N4", "M2-N4", 2695.4, 1.0),
+    line(78, "M3", "M3-N5", "Mg", 2330.4, 1.0),
+    line(78, "M4", "M4,5-N2,3", "Mz", 1592.9, 0.00293255),
+    line(78, "M4", "M4-N6", "Mb", 2127.5, 0.997068),
+    line(78, "M5", "M5-N6,7", "Ma", 2047.5, 1.0),
+    line(79, "K", "K-L1", "Ka3", 66372.0, 0.000344535),
+    line(79, "K", "K-L2", "Ka2", 66991.0, 0.289617),
+    line(79, "K", "K-L3", "Ka1", 68806.0, 0.493048),
+    line(79, "K", "K-M2", "Kb3", 77577.0, 0.056366),
+    line(79, "K", "K-M3", "Kb1", 77982.0, 0.108983),
+    line(79, "K", "K-M4,5", "Kb5", 78434.0, 0.00297764),
+    line(79, "K", "K-N2,3", "Kb2", 80082.3, 0.0478264),
+    line(79, "K", "K-N4,5", "Kb4", 80371.8, 0.000837489),
+    line(79, "L1", "L1-M2", "Lb4", 11205.0, 0.328877),
+    line(79, "L1", "L1-M3", "Lb3", 11610.0, 0.419432),
+    line(79, "L1", "L1-N2", "Lg2", 13710.3, 0.107082),
+    line(79, "L1", "L1-N3", "Lg3", 13806.7, 0.144608),
+    line(79, "L2", "L2-M1", "Ln", 10309.0, 0.0178699),
+    line(79, "L2", "L2-M4", "Lb1", 11443.0, 0.798815),
+    line(79, "L2", "L2-N4", "Lg1", 13380.8, 0.161187),
+    line(79, "L2", "L2-O4", "Lg6", 13659.8, 0.022128),
+    line(79, "L3", "L3-M1", "Ll", 8494.0, 0.0363106),
+    line(79, "L3", "L3-M4", "La2", 9628.0, 0.0772648),
+    line(79, "L3", "L3-M5", "La1", 9713.0, 0.692096),
+    line(79, "L3", "L3-N1", "Lb6", 11156.9, 0.0101227),
+    line(79, "L3", "L3-N4,5", "Lb2,15", 11565.8, 0.168153),
+    line(79, "L3", "L3-O4,5", "Lb5", 11919.0, 0.0160532),
+    line(79, "M2", "M2-N4", "M2-N4", 2794.8, 1.0),
+    line(79, "M3", "M3-N5", "Mg", 2407.9, 1.0),
+    line(79, "M4", "M4,5-N2,3", "Mz", 1648.3, 0.00293255),
+    line(79, "M4", "M4-N6", "Mb", 2203.4, 0.997068),
+    line(79, "M5", "M5-N6,7", "Ma", 2118.4, 1.0),
+    line(80, "K", "K-L1", "Ka3", 68263.0, 0.00037749),
+    line(80, "K", "K-L2", "Ka2", 68893.0, 0.29001),
+    line(80, "K", "K-L3", "Ka1", 70818.0, 0.491626),
+    line(80, "K", "K-M2", "Kb3", 79823.0, 0.0564235),
+    line(80, "K", "K-M3", "Kb1", 80255.0, 0.109136),
+    line(80, "K", "K-M4,5", "Kb5", 80717.0, 0.00306769),
+    line(80, "K", "K-N2,3", "Kb2", 82421.8, 0.048475),
+    line(80, "K", "K-N4,5", "Kb4", 82723.8, 0.00088423),
+    line(80, "L1", "L1-M2", "Lb4", 11560.0, 0.332096),
+    line(80, "L1", "L1-M3", "Lb3", 11992.0, 0.413547),
+    line(80, "L1", "L1-N2", "Lg2", 14158.8, 0.109451),
+    line(80, "L1", "L1-N3", "Lg3", 14262.4, 0.144907),
+    line(80, "L2", "L2-M1", "Ln", 10647.0, 0.0179318),
+    line(80, "L2", "L2-M4", "Lb1", 11824.0, 0.795004),
+    line(80, "L2", "L2-N4", "Lg1", 13830.8, 0.162442),
+    line(80, "L2", "L2-O4", "Lg6", 14125.9, 0.0246217),
+    line(80, "L3", "L3-M1", "Ll", 8722.0, 0.0368335),
+    line(80, "L3", "L3-M4", "La2", 9899.0, 0.0769407),
+    line(80, "L3", "L3-M5", "La1", 9989.0, 0.689105),
+    line(80, "L3", "L3-N1", "Lb6", 11481.8, 0.0102999),
+    line(80, "L3", "L3-N4,5", "Lb2,15", 11905.8, 0.168812),
+    line(80, "L3", "L3-O4,5", "Lb5", 12274.4, 0.0180083),
+    line(80, "M2", "M2-N4", "M2-N4", 2900.8, 1.0),
+    line(80, "M3", "M3-N5", "Mg", 2488.2, 1.0),
+    line(80, "M4", "M4,5-N2,3", "Mz", 1704.8, 0.00293255),
+    line(80, "M4", "M4-N6", "Mb", 2281.0, 0.997068),
+    line(80, "M5", "M5-N6,7", "Ma", 2191.0, 1.0),
+    line(81, "K", "K-L1", "Ka3", 70183.0, 0.000413248),
+    line(81, "K", "K-L2", "Ka2", 70832.0, 0.2904),
+    line(81, "K", "K-L3", "Ka1", 72872.0, 0.49021),
+    line(81, "K", "K-M2", "Kb3", 82114.0, 0.0564398),
+    line(81, "K", "K-M3", "Kb1", 82573.0, 0.109231),
+    line(81, "K", "K-M4,5", "Kb5", 83045.0, 0.00315723),
+    line(81, "K", "K-N2,3", "Kb2", 84809.5, 0.0492149),
+    line(81, "K", "K-N4,5", "Kb4", 85124.3, 0.000933546),
+    line(81, "L1", "L1-M2", "Lb4", 11931.0, 0.335342),
+    line(81, "L1", "L1-M3", "Lb3", 12390.0, 0.407519),
+    line(81, "L1", "L1-N2", "Lg2", 14626.5, 0.112015),
+    line(81, "L1", "L1-N3", "Lg3", 14737.5, 0.145123),
+    line(81, "L2", "L2-M1", "Ln", 10994.0, 0.0180142),
+    line(81, "L2", "L2-M4", "Lb1", 12213.0, 0.791335),
+    line(81, "L2", "L2-N4", "Lg1", 14292.3, 0.163764),
+    line(81, "L2", "L2-O4", "Lg6", 14603.4, 0.0268873),
+    line(81, "L3", "L3-M1", "Ll", 8954.0, 0.0373807),
+    line(81, "L3", "L3-M4", "La2", 10173.0, 0.076633),
+    line(81, "L3", "L3-M5", "La1", 10269.0, 0.686262),
+    line(81, "L3", "L3-N1", "Lb6", 11811.8, 0.0104775),
+    line(81, "L3", "L3-N4,5", "Lb2,15", 12252.3, 0.169347),
+    line(81, "L3", "L3-O4,5", "Lb5", 12643.3, 0.0198991),
+    line(81, "M2", "M2-N4", "M2-N4", 3010.3, 1.0),
+    line(81, "M3", "M3-N5", "Mg", 2572.0, 1.0),
+    line(81, "M4", "M4,5-N2,3", "Mz", 1764.5, 0.00293255),
+    line(81, "M4", "M4-N6", "Mb", 2362.8, 0.997068),
+    line(81, "M5", "M5-N6,7", "Ma", 2266.8, 1.0),
+    line(82, "K", "K-L1", "Ka3", 72144.0, 0.000451879),
+    line(82, "K", "K-L2", "Ka2", 72805.0, 0.290794),
+    line(82, "K", "K-L3", "Ka1", 74970.0, 0.488729),
+    line(82, "K", "K-M2", "Kb3", 84451.0, 0.0564712),
+    line(82, "K", "K-M3", "Kb1", 84939.0, 0.109334),
+    line(82, "K", "K-M4,5", "Kb5", 85419.0, 0.00324575),
+    line(82, "K", "K-N2,3", "Kb2", 87243.1, 0.0499891),
+    line(82, "K", "K-N4,5", "Kb4", 87570.7, 0.000985296),
+    line(82, "L1", "L1-M2", "Lb4", 12307.0, 0.338604),
+    line(82, "L1", "L1-M3", "Lb3", 12795.0, 0.401364),
+    line(82, "L1", "L1-N2", "Lg2", 15099.1, 0.114773),
+    line(82, "L1", "L1-N3", "Lg3", 15217.5, 0.145259),
+    line(82, "L2", "L2-M1", "Ln", 11349.0, 0.0181158),
+    line(82, "L2", "L2-M4", "Lb1", 12614.0, 0.787801),
+    line(82, "L2", "L2-N4", "Lg1", 14765.7, 0.16515),
+    line(82, "L2", "L2-O4", "Lg6", 15093.6, 0.0289336),
+    line(82, "L3", "L3-M1", "Ll", 9184.0, 0.0379506),
+    line(82, "L3", "L3-M4", "La2", 10449.0, 0.0763387),
+    line(82, "L3", "L3-M5", "La1", 10551.0, 0.68354),
+    line(82, "L3", "L3-N1", "Lb6", 12143.2, 0.0106551),
+    line(82, "L3", "L3-N4,5", "Lb2,15", 12600.7, 0.169789),
+    line(82, "L3", "L3-O4,5", "Lb5", 13014.3, 0.0217266),
+    line(82, "M2", "M2-N4", "M2-N4", 3119.7, 1.0),
+    line(82, "M3", "M3-N5", "Mg", 2653.8, 1.0),
+    line(82, "M4", "M4,5-N2,3", "Mz", 1824.1, 0.00293255),
+    line(82, "M4", "M4-N6", "Mb", 2444.3, 0.997068),
+    line(82, "M5", "M5-N6,7", "Ma", 2342.3, 1.0),
+    line(83, "K", "K-L1", "Ka3", 74138.0, 0.000493524),
+    line(83, "K", "K-L2", "Ka2", 74815.0, 0.291236),
+    line(83, "K", "K-L3", "Ka1", 77107.0, 0.487208),
+    line(83, "K", "K-M2", "Kb3", 86830.0, 0.0564887),
+    line(83, "K", "K-M3", "Kb1", 87349.0, 0.109418),
+    line(83, "K", "K-M4,5", "Kb5", 87838.0, 0.00333342),
+    line(83, "K", "K-N2,3", "Kb2", 89720.8, 0.0507831),
+    line(83, "K", "K-N4,5", "Kb4", 90062.0, 0.00103951),
+    line(83, "L1", "L1-M2", "Lb4", 12692.0, 0.341869),
+    line(83, "L1", "L1-M3", "Lb3", 13211.0, 0.395094),
+    line(83, "L1", "L1-N2", "Lg2", 15582.8, 0.117723),
+    line(83, "L1", "L1-N3", "Lg3", 15709.2, 0.145314),
+    line(83, "L2", "L2-M1", "Ln", 11712.0, 0.0182354),
+    line(83, "L2", "L2-M4", "Lb1", 13023.0, 0.784398),
+    line(83, "L2", "L2-N4", "Lg1", 15247.0, 0.166598),
+    line(83, "L2", "L2-O4", "Lg6", 15592.0, 0.0307689),
+    line(83, "L3", "L3-M1", "Ll", 9420.0, 0.0385409),
+    line(83, "L3", "L3-M4", "La2", 10731.0, 0.0760535),
+    line(83, "L3", "L3-M5", "La1", 10839.0, 0.6809),
+    line(83, "L3", "L3-N1", "Lb6", 12480.0, 0.0108323),
+    line(83, "L3", "L3-N4,5", "Lb2,15", 12955.0, 0.170182),
+    line(83, "L3", "L3-O4,5", "Lb5", 13392.1, 0.0234912),
+    line(83, "M2", "M2-N4", "M2-N4", 3232.0, 1.0),
+    line(83, "M3", "M3-N5", "Mg", 2736.9, 1.0),
+    line(83, "M4", "M4,5-N2,3", "Mz", 1882.8, 0.00293255),
+    line(83, "M4", "M4-N6", "Mb", 2525.7, 0.997068),
+    line(83, "M5", "M5-N6,7", "Ma", 2417.7, 1.0),
+    line(84, "K", "K-L1", "Ka3", 76166.0, 0.000538349),
+    line(84, "K", "K-L2", "Ka2", 76861.0, 0.291676),
+    line(84, "K", "K-L3", "Ka1", 79291.0, 0.485694),
+    line(84, "K", "K-M2", "Kb3", 89251.0, 0.0565057),
+    line(84, "K", "K-M3", "Kb1", 89803.0, 0.1095),
+    line(84, "K", "K-M4,5", "Kb5", 90307.0, 0.00342052),
+    line(84, "K", "K-N2,3", "Kb2", 92254.0, 0.0515692),
+    line(84, "K", "K-N4,5", "Kb4", 92605.0, 0.00109629),
+    line(84, "L1", "L1-M2", "Lb4", 13085.0, 0.345126),
+    line(84, "L1", "L1-M3", "Lb3", 13637.0, 0.388724),
+    line(84, "L1", "L1-N2", "Lg2", 16088.0, 0.12086),
+    line(84, "L1", "L1-N3", "Lg3", 16234.0, 0.145291),
+    line(84, "L2", "L2-M1", "Ln", 12095.0, 0.0183717),
+    line(84, "L2", "L2-M4", "Lb1", 13446.0, 0.78112),
+    line(84, "L2", "L2-N4", "Lg1", 15744.0, 0.168107),
+    line(84, "L2", "L2-O4", "Lg6", 16112.0, 0.0324015),
+    line(84, "L3", "L3-M1", "Ll", 9665.0, 0.0391491),
+    line(84, "L3", "L3-M4", "La2", 11016.0, 0.0757727),
+    line(84, "L3", "L3-M5", "La1", 11131.0, 0.6783),
+    line(84, "L3", "L3-N1", "Lb6", 12819.0, 0.0110084),
+    line(84, "L3", "L3-N4,5", "Lb2,15", 13314.0, 0.170577),
+    line(84, "L3", "L3-O4,5", "Lb5", 13783.0, 0.0251925),
+    line(84, "M2", "M2-N4", "M2-N4", 3354.0, 1.0),
+    line(84, "M3", "M3-N5", "Mg", 2829.0, 1.0),
+    line(84, "M4", "M4,5-N2,3", "Mz", 1947.0, 0.00293255),
+    line(84, "M4", "M4-N6", "Mb", 2614.0, 0.997068),
+    line(84, "M5", "M5-N6,7", "Ma", 2499.0, 1.0),
+    line(85, "K", "K-L1", "Ka3", 78237.0, 0.000586485),
+    line(85, "K", "K-L2", "Ka2", 78945.0, 0.292111),
+    line(85, "K", "K-L3", "Ka1", 81516.0, 0.484189),
+    line(85, "K", "K-M2", "Kb3", 91722.0, 0.0565223),
+    line(85, "K", "K-M3", "Kb1", 92304.0, 0.109582),
+    line(85, "K", "K-M4,5", "Kb5", 92821.0, 0.00350707),
+    line(85, "K", "K-N2,3", "Kb2", 94844.0, 0.0523472),
+    line(85, "K", "K-N4,5", "Kb4", 95197.0, 0.00115563),
+    line(85, "L1", "L1-M2", "Lb4", 13485.0, 0.348363),
+    line(85, "L1", "L1-M3", "Lb3", 14067.0, 0.382266),
+    line(85, "L1", "L1-N2", "Lg2", 16607.0, 0.12418),
+    line(85, "L1", "L1-N3", "Lg3", 16753.0, 0.145191),
+    line(85, "L2", "L2-M1", "Ln", 12468.0, 0.0185236),
+    line(85, "L2", "L2-M4", "Lb1", 13876.0, 0.777965),
+    line(85, "L2", "L2-N4", "Lg1", 16252.0, 0.169672),
+    line(85, "L2", "L2-O4", "Lg6", 16637.0, 0.0338391),
+    line(85, "L3", "L3-M1", "Ll", 9897.0, 0.0397749),
+    line(85, "L3", "L3-M4", "La2", 11305.0, 0.0754964),
+    line(85, "L3", "L3-M5", "La1", 11427.0, 0.675741),
+    line(85, "L3", "L3-N1", "Lb6", 13172.0, 0.0111836),
+    line(85, "L3", "L3-N4,5", "Lb2,15", 13681.0, 0.170973),
+    line(85, "L3", "L3-O4,5", "Lb5", 14174.0, 0.0268316),
+    line(85, "M2", "M2-N4", "M2-N4", 3475.0, 1.0),
+    line(85, "M3", "M3-N5", "Mg", 2919.0, 1.0),
+    line(85, "M4", "M4,5-N2,3", "Mz", 2023.0, 0.00293255),
+    line(85, "M4", "M4-N6", "Mb", 2699.0, 0.997068),
+    line(85, "M5", "M5-N6,7", "Ma", 2577.0, 1.0),
+    line(86, "K", "K-L1", "Ka3", 80355.0, 0.00063801),
+    line(86, "K", "K-L2", "Ka2", 81067.0, 0.292623),
+    line(86, "K", "K-L3", "Ka1", 83785.0, 0.482653),
+    line(86, "K", "K-M2", "Kb3", 94245.0, 0.0565185),
+    line(86, "K", "K-M3", "Kb1", 94866.0, 0.109676),
+    line(86, "K", "K-M4,5", "Kb5", 95382.0, 0.0035928),
+    line(86, "K", "K-N2,3", "Kb2", 97475.0, 0.053081),
+    line(86, "K", "K-N4,5", "Kb4", 97837.0, 0.00121745),
+    line(86, "L1", "L1-M2", "Lb4", 13890.0, 0.351569),
+    line(86, "L1", "L1-M3", "Lb3", 14511.0, 0.375735),
+    line(86, "L1", "L1-N2", "Lg2", 17120.0, 0.127679),
+    line(86, "L1", "L1-N3", "Lg3", 17281.0, 0.145017),
+    line(86, "L2", "L2-M1", "Ln", 12855.0, 0.01869),
+    line(86, "L2", "L2-M4", "Lb1", 14315.0, 0.774929),
+    line(86, "L2", "L2-N4", "Lg1", 16770.0, 0.171292),
+    line(86, "L2", "L2-O4", "Lg6", 17173.0, 0.0350889),
+    line(86, "L3", "L3-M1", "Ll", 10137.0, 0.0404173),
+    line(86, "L3", "L3-M4", "La2", 11597.0, 0.0752231),
+    line(86, "L3", "L3-M5", "La1", 11727.0, 0.673209),
+    line(86, "L3", "L3-N1", "Lb6", 13522.0, 0.0113576),
+    line(86, "L3", "L3-N4,5", "Lb2,15", 14052.0, 0.171384),
+    line(86, "L3", "L3-O4,5", "Lb5", 14571.0, 0.0284085),
+    line(86, "M2", "M2-N4", "M2-N4", 3592.0, 1.0),
+    line(86, "M3", "M3-N5", "Mg", 2997.0, 1.0),
+    line(86, "M4", "M4,5-N2,3", "Mz", 2093.0, 0.00293255),
+    line(86, "M4", "M4-N6", "Mb", 2784.0, 0.997068),
+    line(86, "M5", "M5-N6,7", "Ma", 2654.0, 1.0),
+    line(87, "K", "K-L1", "Ka3", 82498.0, 0.000693087),
+    line(87, "K", "K-L2", "Ka2", 83230.0, 0.29313),
+    line(87, "K", "K-L3", "Ka1", 86106.0, 0.481125),
+    line(87, "K", "K-M2", "Kb3", 96810.0, 0.0565141),
+    line(87, "K", "K-M3", "Kb1", 97474.0, 0.10977),
+    line(87, "K", "K-M4,5", "Kb5", 98001.0, 0.00367797),
+    line(87, "K", "K-N2,3", "Kb2", 100157.0, 0.053807),
+    line(87, "K", "K-N4,5", "Kb4", 100534.0, 0.00128182),
+    line(87, "L1", "L1-M2", "Lb4", 14312.0, 0.354736),
+    line(87, "L1", "L1-M3", "Lb3", 14976.0, 0.369144),
+    line(87, "L1", "L1-N2", "Lg2", 17659.0, 0.13135),
+    line(87, "L1", "L1-N3", "Lg3", 17829.0, 0.14477),
+    line(87, "L2", "L2-M1", "Ln", 13255.0, 0.0188699),
+    line(87, "L2", "L2-M4", "Lb1", 14771.0, 0.772012),
+    line(87, "L2", "L2-N4", "Lg1", 17304.0, 0.172959),
+    line(87, "L2", "L2-O4", "Lg6", 17725.0, 0.0361586),
+    line(87, "L3", "L3-M1", "Ll", 10379.0, 0.0410748),
+    line(87, "L3", "L3-M4", "La2", 11895.0, 0.0749503),
+    line(87, "L3", "L3-M5", "La1", 12031.0, 0.670683),
+    line(87, "L3", "L3-N1", "Lb6", 13878.0, 0.01153),
+    line(87, "L3", "L3-N4,5", "Lb2,15", 14428.0, 0.171838),
+    line(87, "L3", "L3-O4,5", "Lb5", 14973.0, 0.0299233),
+    line(87, "M2", "M2-N4", "M2-N4", 3724.0, 1.0),
+    line(87, "M3", "M3-N5", "Mg", 3086.0, 1.0),
+    line(87, "M4", "M4,5-N2,3", "Mz", 2156.0, 0.00293255),
+    line(87, "M4", "M4-N6", "Mb", 2868.0, 0.997068),
+    line(87, "M5", "M5-N6,7", "Ma", 2732.0, 1.0),
+    line(88, "K", "K-L1", "Ka3", 84685.0, 0.000751841),
+    line(88, "K", "K-L2", "Ka2", 85438.0, 0.293634),
+    line(88, "K", "K-L3", "Ka1", 88478.0, 0.479605),
+    line(88, "K", "K-M2", "Kb3", 99432.0, 0.0565093),
+    line(88, "K", "K-M3", "Kb1", 100130.0, 0.109863),
+    line(88, "K", "K-M4,5", "Kb5", 100674.0, 0.00376259),
+    line(88, "K", "K-N2,3", "Kb2", 102864.0, 0.0545253),
+    line(88, "K", "K-N4,5", "Kb4", 103286.0, 0.00134876),
+    line(88, "L1", "L1-M2", "Lb4", 14747.0, 0.357854),
+    line(88, "L1", "L1-M3", "Lb3", 15445.0, 0.362506),
+    line(88, "L1", "L1-N2", "Lg2", 18179.0, 0.135187),
+    line(88, "L1", "L1-N3", "Lg3", 18358.0, 0.144453),
+    line(88, "L2", "L2-M1", "Ln", 13662.0, 0.0190621),
+    line(88, "L2", "L2-M4", "Lb1", 15236.0, 0.769212),
+    line(88, "L2", "L2-N4", "Lg1", 17848.0, 0.174671),
+    line(88, "L2", "L2-O4", "Lg6", 18284.0, 0.0370551),
+    line(88, "L3", "L3-M1", "Ll", 10622.0, 0.0417479),
+    line(88, "L3", "L3-M4", "La2", 12196.0, 0.0746801),
+    line(88, "L3", "L3-M5", "La1", 12339.0, 0.668181),
+    line(88, "L3", "L3-N1", "Lb6", 14236.0, 0.0117012),
+    line(88, "L3", "L3-N4,5", "Lb2,15", 14808.0, 0.172313),
+    line(88, "L3", "L3-O4,5", "Lb5", 15376.0, 0.0313773),
+    line(88, "M2", "M2-N4", "M2-N4", 3854.0, 1.0),
+    line(88, "M3", "M3-N5", "Mg", 3189.0, 1.0),
+    line(88, "M4", "M4,5-N2,3", "Mz", 2190.0, 0.00293255),
+    line(88, "M4", "M4-N6", "Mb", 2949.0, 0.997068),
+    line(88, "M5", "M5-N6,7", "Ma", 2806.0, 1.0),
+    line(89, "K", "K-L1", "Ka3", 86915.0, 0.000814391),
+    line(89, "K", "K-L2", "Ka2", 87672.0, 0.294132),
+    line(89, "K", "K-L3", "Ka1", 90884.0, 0.478093),
+    line(89, "K", "K-M2", "Kb3", 102099.0, 0.056504),
+    line(89, "K", "K-M3", "Kb1", 102846.0, 0.109956),
+    line(89, "K", "K-M4,5", "Kb5", 103385.0, 0.00384666),
+    line(89, "K", "K-N2,3", "Kb2", 105675.0, 0.0552358),
+    line(89, "K", "K-N4,5", "Kb4", 106080.0, 0.00141826),
+    line(89, "L1", "L1-M2", "Lb4", 15184.0, 0.360914),
+    line(89, "L1", "L1-M3", "Lb3", 15931.0, 0.355834),
+    line(89, "L1", "L1-N2", "Lg2", 18760.0, 0.139184),
+    line(89, "L1", "L1-N3", "Lg3", 18950.0, 0.144068),
+    line(89, "L2", "L2-M1", "Ln", 14081.0, 0.0192658),
+    line(89, "L2", "L2-M4", "Lb1", 15713.0, 0.766527),
+    line(89, "L2", "L2-N4", "Lg1", 18408.0, 0.176422),
+    line(89, "L2", "L2-O4", "Lg6", 18868.0, 0.0377856),
+    line(89, "L3", "L3-M1", "Ll", 10869.0, 0.042437),
+    line(89, "L3", "L3-M4", "La2", 12501.0, 0.074413),
+    line(89, "L3", "L3-M5", "La1", 12652.0, 0.665706),
+    line(89, "L3", "L3-N1", "Lb6", 14602.0, 0.0118714),
+    line(89, "L3", "L3-N4,5", "Lb2,15", 15196.0, 0.172801),
+    line(89, "L3", "L3-O4,5", "Lb5", 15791.0, 0.0327715),
+    line(89, "M2", "M2-N4", "M2-N4", 3981.0, 1.0),
+    line(89, "M3", "M3-N5", "Mg", 3270.0, 1.0),
+    line(89, "M4", "M4,5-N2,3", "Mz", 2290.0, 0.00293255),
+    line(89, "M4", "M4-N6", "Mb", 3051.0, 0.997068),
+    line(89, "M5", "M5-N6,7", "Ma", 2900.0, 1.0),
+    line(90, "K", "K-L1", "Ka3", 89179.0, 0.000880854),
+    line(90, "K", "K-L2", "Ka2", 89958.0, 0.294627),
+    line(90, "K", "K-L3", "Ka1", 93351.0, 0.476588),
+    line(90, "K", "K-M2", "Kb3", 104821.0, 0.0564982),
+    line(90, "K", "K-M3", "Kb1", 105605.0, 0.110047),
+    line(90, "K", "K-M4,5", "Kb5", 106160.0, 0.00393018),
+    line(90, "K", "K-N2,3", "Kb2", 108483.0, 0.0559385),
+    line(90, "K", "K-N4,5", "Kb4", 108939.0, 0.00149032),
+    line(90, "L1", "L1-M2", "Lb4", 15642.0, 0.363908),
+    line(90, "L1", "L1-M3", "Lb3", 16426.0, 0.34914),
+    line(90, "L1", "L1-N2", "Lg2", 19304.0, 0.143333),
+    line(90, "L1", "L1-N3", "Lg3", 19505.6, 0.143619),
+    line(90, "L2", "L2-M1", "Ln", 14511.0, 0.01948),
+    line(90, "L2", "L2-M4", "Lb1", 16202.0, 0.76396),
+    line(90, "L2", "L2-N4", "Lg1", 18980.9, 0.178203),
+    line(90, "L2", "L2-O4", "Lg6", 19464.0, 0.0383567),
+    line(90, "L3", "L3-M1", "Ll", 11118.0, 0.043142),
+    line(90, "L3", "L3-M4", "La2", 12809.0, 0.0741494),
+    line(90, "L3", "L3-M5", "La1", 12968.0, 0.663264),
+    line(90, "L3", "L3-N1", "Lb6", 14970.0, 0.0120405),
+    line(90, "L3", "L3-N4,5", "Lb2,15", 15587.9, 0.173297),
+    line(90, "L3", "L3-O4,5", "Lb5", 16207.5, 0.0341068),
+    line(90, "M2", "M2-N4", "M2-N4", 4117.9, 1.0),
+    line(90, "M3", "M3-N5", "Mg", 3370.8, 1.0),
+    line(90, "M4", "M4,5-N2,3", "Mz", 2323.0, 0.00293255),
+    line(90, "M4", "M4-N6", "Mb", 3148.6, 0.997068),
+    line(90, "M5", "M5-N6,7", "Ma", 2989.6, 1.0),
+    line(91, "K", "K-L1", "Ka3", 91496.0, 0.000951514),
+    line(91, "K", "K-L2", "Ka2", 92287.0, 0.295295),
+    line(91, "K", "K-L3", "Ka1", 95868.0, 0.475172),
+    line(91, "K", "K-M2", "Kb3", 107600.0, 0.056488),
+    line(91, "K", "K-M3", "Kb1", 108427.0, 0.110156),
+    line(91, "K", "K-M4,5", "Kb5", 108990.0, 0.00401385),
+    line(91, "K", "K-N2,3", "Kb2", 111377.0, 0.0563582),
+    line(91, "K", "K-N4,5", "Kb4", 111858.0, 0.00156521),
+    line(91, "L1", "L1-M2", "Lb4", 16104.0, 0.366831),
+    line(91, "L1", "L1-M3", "Lb3", 16931.0, 0.342436),
+    line(91, "L1", "L1-N2", "Lg2", 19881.0, 0.147626),
+    line(91, "L1", "L1-N3", "Lg3", 20098.0, 0.143108),
+    line(91, "L2", "L2-M1", "Ln", 14947.0, 0.0197038),
+    line(91, "L2", "L2-M4", "Lb1", 16703.0, 0.761511),
+    line(91, "L2", "L2-N4", "Lg1", 19571.0, 0.18001),
+    line(91, "L2", "L2-O4", "Lg6", 20082.0, 0.0387752),
+    line(91, "L3", "L3-M1", "Ll", 11366.0, 0.0438641),
+    line(91, "L3", "L3-M4", "La2", 13122.0, 0.0738915),
+    line(91, "L3", "L3-M5", "La1", 13291.0, 0.660874),
+    line(91, "L3", "L3-N1", "Lb6", 15346.0, 0.012209),
+    line(91, "L3", "L3-N4,5", "Lb2,15", 15990.0, 0.173776),
+    line(91, "L3", "L3-O4,5", "Lb5", 16639.0, 0.0353851),
+    line(91, "M2", "M2-N4", "M2-N4", 4258.0, 1.0),
+    line(91, "M3", "M3-N5", "Mg", 3466.0, 1.0),
+    line(91, "M4", "M4,5-N2,3", "Mz", 2387.0, 0.00293255),
+    line(91, "M4", "M4-N6", "Mb", 3240.0, 0.997068),
+    line(91, "M5", "M5-N6,7", "Ma", 3071.0, 1.0),
+    line(92, "K", "K-L1", "Ka3", 93849.0, 0.00102634),
+    line(92, "K", "K-L2", "Ka2", 94658.0, 0.295959),
+    line(92, "K", "K-L3", "Ka1", 98440.0, 0.473762),
+    line(92, "K", "K-M2", "Kb3", 110424.0, 0.0564773),
+    line(92, "K", "K-M3", "Kb1", 111303.0, 0.110264),
+    line(92, "K", "K-M4,5", "Kb5", 111878.0, 0.004097),
+    line(92, "K", "K-N2,3", "Kb2", 114335.0, 0.0567721),
+    line(92, "K", "K-N4,5", "Kb4", 114828.0, 0.0016427),
+    line(92, "L1", "L1-M2", "Lb4", 16575.0, 0.369674),
+    line(92, "L1", "L1-M3", "Lb3", 17454.0, 0.335732),
+    line(92, "L1", "L1-N2", "Lg2", 20486.0, 0.152055),
+    line(92, "L1", "L1-N3", "Lg3", 20714.0, 0.142538),
+    line(92, "L2", "L2-M1", "Ln", 15400.0, 0.0199364),
+    line(92, "L2", "L2-M4", "Lb1", 17220.0, 0.759181),
+    line(92, "L2", "L2-N4", "Lg1", 20169.7, 0.181836),
+    line(92, "L2", "L2-O4", "Lg6", 20691.0, 0.039047),
+    line(92, "L3", "L3-M1", "Ll", 11618.0, 0.0446057),
+    line(92, "L3", "L3-M4", "La2", 13438.0, 0.0736434),
+    line(92, "L3", "L3-M5", "La1", 13614.0, 0.658571),
+    line(92, "L3", "L3-N1", "Lb6", 15727.0, 0.0123777),
+    line(92, "L3", "L3-N4,5", "Lb2,15", 16387.7, 0.174193),
+    line(92, "L3", "L3-O4,5", "Lb5", 17063.2, 0.0366091),
+    line(92, "M2", "M2-N4", "M2-N4", 4403.7, 1.0),
+    line(92, "M3", "M3-N5", "Mg", 3566.8, 1.0),
+    line(92, "M4", "M4,5-N2,3", "Mz", 2457.0, 0.00293255),
+    line(92, "M4", "M4-N6", "Mb", 3339.8, 0.997068),
+    line(92, "M5", "M5-N6,7", "Ma", 3163.8, 1.0),
+    line(93, "K", "K-L1", "Ka3", 96242.0, 0.0011053),
+    line(93, "K", "K-L2", "Ka2", 97069.0, 0.296693),
+    line(93, "K", "K-L3", "Ka1", 101059.0, 0.472291),
+    line(93, "K", "K-M2", "Kb3", 113303.0, 0.0564474),
+    line(93, "K", "K-M3", "Kb1", 114234.0, 0.110378),
+    line(93, "K", "K-M4,5", "Kb5", 114820.0, 0.00417905),
+    line(93, "K", "K-N2,3", "Kb2", 117341.0, 0.0571839),
+    line(93, "K", "K-N4,5", "Kb4", 117853.0, 0.00172253),
+    line(93, "L1", "L1-M2", "Lb4", 17061.0, 0.372433),
+    line(93, "L1", "L1-M3", "Lb3", 17992.0, 0.329041),
+    line(93, "L1", "L1-N2", "Lg2", 21099.0, 0.156614),
+    line(93, "L1", "L1-N3", "Lg3", 21342.0, 0.141912),
+    line(93, "L2", "L2-M1", "Ln", 15861.0, 0.0201769),
+    line(93, "L2", "L2-M4", "Lb1", 17751.0, 0.756974),
+    line(93, "L2", "L2-N4", "Lg1", 20784.0, 0.18367),
+    line(93, "L2", "L2-O4", "Lg6", 21326.0, 0.0391785),
+    line(93, "L3", "L3-M1", "Ll", 11871.0, 0.0453684),
+    line(93, "L3", "L3-M4", "La2", 13761.0, 0.073407),
+    line(93, "L3", "L3-M5", "La1", 13946.0, 0.656374),
+    line(93, "L3", "L3-N1", "Lb6", 16109.0, 0.0125468),
+    line(93, "L3", "L3-N4,5", "Lb2,15", 16794.0, 0.174522),
+    line(93, "L3", "L3-O4,5", "Lb5", 17501.0, 0.0377811),
+    line(93, "M2", "M2-N4", "M2-N4", 4550.0, 1.0),
+    line(93, "M3", "M3-N5", "Mg", 3664.0, 1.0),
+    line(93, "M4", "M4,5-N2,3", "Mz", 2521.0, 0.00293255),
+    line(93, "M4", "M4-N6", "Mb", 3435.0, 0.997068),
+    line(93, "M5", "M5-N6,7", "Ma", 3250.0, 1.0),
+    line(94, "K", "K-L1", "Ka3", 98687.0, 0.00118865),
+    line(94, "K", "K-L2", "Ka2", 99525.0, 0.297421),
+    line(94, "K", "K-L3", "Ka1", 103734.0, 0.470826),
+    line(94, "K", "K-M2", "Kb3", 116244.0, 0.0564169),
+    line(94, "K", "K-M3", "Kb1", 117228.0, 0.110491),
+    line(94, "K", "K-M4,5", "Kb5", 117821.0, 0.00426057),
+    line(94, "K", "K-N2,3", "Kb2", 120411.0, 0.0575898),
+    line(94, "K", "K-N4,5", "Kb4", 120945.0, 0.00180494),
+    line(94, "L1", "L1-M2", "Lb4", 17557.0, 0.375103),
+    line(94, "L1", "L1-M3", "Lb3", 18541.0, 0.322372),
+    line(94, "L1", "L1-N2", "Lg2", 21724.0, 0.161292),
+    line(94, "L1", "L1-N3", "Lg3", 21981.0, 0.141233),
+    line(94, "L2", "L2-M1", "Ln", 16333.0, 0.0204246),
+    line(94, "L2", "L2-M4", "Lb1", 18296.0, 0.754892),
+    line(94, "L2", "L2-N4", "Lg1", 21420.0, 0.185507),
+    line(94, "L2", "L2-O4", "Lg6", 21983.0, 0.0391761),
+    line(94, "L3", "L3-M1", "Ll", 12124.0, 0.0461541),
+    line(94, "L3", "L3-M4", "La2", 14087.0, 0.0731856),
+    line(94, "L3", "L3-M5", "La1", 14282.0, 0.654311),
+    line(94, "L3", "L3-N1", "Lb6", 16498.0, 0.0127172),
+    line(94, "L3", "L3-N4,5", "Lb2,15", 17211.0, 0.174728),
+    line(94, "L3", "L3-O4,5", "Lb5", 17944.0, 0.0389038),
+    line(94, "M2", "M2-N4", "M2-N4", 4701.0, 1.0),
+    line(94, "M3", "M3-N5", "Mg", 3765.0, 1.0),
+    line(94, "M4", "M4,5-N2,3", "Mz", 2590.0, 0.00293255),
+    line(94, "M4", "M4-N6", "Mb", 3534.0, 0.997068),
+    line(94, "M5", "M5-N6,7", "Ma", 3339.0, 1.0),
+    line(95, "K", "K-L1", "Ka3", 101174.0, 0.00127648),
+    line(95, "K", "K-L2", "Ka2", 102030.0, 0.298143),
+    line(95, "K", "K-L3", "Ka1", 106472.0, 0.469369),
+    line(95, "K", "K-M2", "Kb3", 119243.0, 0.0563859),
+    line(95, "K", "K-M3", "Kb1", 120284.0, 0.110604),
+    line(95, "K", "K-M4,5", "Kb5", 120886.0, 0.00434157),
+    line(95, "K", "K-N2,3", "Kb2", 123544.0, 0.0579898),
+    line(95, "K", "K-N4,5", "Kb4", 124102.0, 0.00188992),
+    line(95, "L1", "L1-M2", "Lb4", 18069.0, 0.377679),
+    line(95, "L1", "L1-M3", "Lb3", 19110.0, 0.315735),
+    line(95, "L1", "L1-N2", "Lg2", 22370.0, 0.166083),
+    line(95, "L1", "L1-N3", "Lg3", 22643.0, 0.140504),
+    line(95, "L2", "L2-M1", "Ln", 16819.0, 0.0206788),
+    line(95, "L2", "L2-M4", "Lb1", 18856.0, 0.75294),
+    line(95, "L2", "L2-N4", "Lg1", 22072.0, 0.187335),
+    line(95, "L2", "L2-O4", "Lg6", 22654.0, 0.0390457),
+    line(95, "L3", "L3-M1", "Ll", 12377.0, 0.0469678),
+    line(95, "L3", "L3-M4", "La2", 14414.0, 0.072986),
+    line(95, "L3", "L3-M5", "La1", 14620.0, 0.652444),
+    line(95, "L3", "L3-N1", "Lb6", 16890.0, 0.0128901),
+    line(95, "L3", "L3-N4,5", "Lb2,15", 17630.0, 0.17473),
+    line(95, "L3", "L3-O4,5", "Lb5", 18394.0, 0.0399821),
+    line(95, "M2", "M2-N4", "M2-N4", 4859.0, 1.0),
+    line(95, "M3", "M3-N5", "Mg", 3869.0, 1.0),
+    line(95, "M4", "M4,5-N2,3", "Mz", 2658.0, 0.00293255),
+    line(95, "M4", "M4-N6", "Mb", 3635.0, 0.997068),
+    line(95, "M5", "M5-N6,7", "Ma", 3429.0, 1.0),
+    line(96, "K", "K-L1", "Ka3", 103715.0, 0.00136891),
+    line(96, "K", "K-L2", "Ka2", 104590.0, 0.298859),
+    line(96, "K", "K-L3", "Ka1", 109271.0, 0.467918),
+    line(96, "K", "K-M2", "Kb3", 122304.0, 0.0563543),
+    line(96, "K", "K-M3", "Kb1", 123403.0, 0.110716),
+    line(96, "K", "K-M4,5", "Kb5", 124017.0, 0.00442204),
+    line(96, "K", "K-N2,3", "Kb2", 126743.0, 0.058384),
+    line(96, "K", "K-N4,5", "Kb4", 127325.0, 0.00197748),
+    line(96, "L1", "L1-M2", "Lb4", 18589.0, 0.380157),
+    line(96, "L1", "L1-M3", "Lb3", 19688.0, 0.309138),
+    line(96, "L1", "L1-N2", "Lg2", 23028.0, 0.170977),
+    line(96, "L1", "L1-N3", "Lg3", 23319.0, 0.139728),
+    line(96, "L2", "L2-M1", "Ln", 17314.0, 0.0209387),
+    line(96, "L2", "L2-M4", "Lb1", 19427.0, 0.751121),
+    line(96, "L2", "L2-N4", "Lg1", 22735.0, 0.189147),
+    line(96, "L2", "L2-O4", "Lg6", 23338.0, 0.0387928),
+    line(96, "L3", "L3-M1", "Ll", 12633.0, 0.0478123),
+    line(96, "L3", "L3-M4", "La2", 14746.0, 0.0728111),
+    line(96, "L3", "L3-M5", "La1", 14961.0, 0.650799),
+    line(96, "L3", "L3-N1", "Lb6", 17286.0, 0.0130663),
+    line(96, "L3", "L3-N4,5", "Lb2,15", 18054.0, 0.174492),
+    line(96, "L3", "L3-O4,5", "Lb5", 18846.0, 0.0410192),
+    line(96, "M2", "M2-N4", "M2-N4", 5021.0, 1.0),
+    line(96, "M3", "M3-N5", "Mg", 3976.0, 1.0),
+    line(96, "M4", "M4,5-N2,3", "Mz", 2726.0, 0.00293255),
+    line(96, "M4", "M4-N6", "Mb", 3740.0, 0.997068),
+    line(96, "M5", "M5-N6,7", "Ma", 3525.0, 1.0),
+    line(97, "K", "K-L1", "Ka3", 106300.0, 0.00146598),
+    line(97, "K", "K-L2", "Ka2", 107185.0, 0.299672),
+    line(97, "K", "K-L3", "Ka1", 112121.0, 0.466452),
+    line(97, "K", "K-M2", "Kb3", 125418.0, 0.0563087),
+    line(97, "K", "K-M3", "Kb1", 126580.0, 0.110844),
+    line(97, "K", "K-M4,5", "Kb5", 127203.0, 0.00450179),
+    line(97, "K", "K-N2,3", "Kb2", 129998.0, 0.0586879),
+    line(97, "K", "K-N4,5", "Kb4", 130601.0, 0.00206751),
+    line(97, "L1", "L1-M2", "Lb4", 19118.0, 0.382535),
+    line(97, "L1", "L1-M3", "Lb3", 20280.0, 0.302591),
+    line(97, "L1", "L1-N2", "Lg2", 23698.0, 0.175967),
+    line(97, "L1", "L1-N3", "Lg3", 24007.0, 0.138908),
+    line(97, "L2", "L2-M1", "Ln", 17826.0, 0.0212037),
+    line(97, "L2", "L2-M4", "Lb1", 20018.0, 0.74944),
+    line(97, "L2", "L2-N4", "Lg1", 23416.0, 0.190933),
+    line(97, "L2", "L2-O4", "Lg6", 24045.0, 0.0384235),
+    line(97, "L3", "L3-M1", "Ll", 12890.0, 0.048692),
+    line(97, "L3", "L3-M4", "La2", 15082.0, 0.0726666),
+    line(97, "L3", "L3-M5", "La1", 15308.0, 0.649425),
+    line(97, "L3", "L3-N1", "Lb6", 17687.0, 0.013247),
+    line(97, "L3", "L3-N4,5", "Lb2,15", 18480.0, 0.173949),
+    line(97, "L3", "L3-O4,5", "Lb5", 19305.0, 0.0420199),
+    line(97, "M2", "M2-N4", "M2-N4", 5183.0, 1.0),
+    line(97, "M3", "M3-N5", "Mg", 4078.0, 1.0),
+    line(97, "M4", "M4,5-N2,3", "Mz", 2795.0, 0.00293255),
+    line(97, "M4", "M4-N6", "Mb", 3842.0, 0.997068),
+    line(97, "M5", "M5-N6,7", "Ma", 3616.0, 1.0),
+    line(98, "K", "K-L1", "Ka3", 108929.0, 0.00156786),
+    line(98, "K", "K-L2", "Ka2", 109831.0, 0.300478),
+    line(98, "K", "K-L3", "Ka1", 115032.0, 0.464993),
+    line(98, "K", "K-M2", "Kb3", 128594.0, 0.0562625),
+    line(98, "K", "K-M3", "Kb1", 129823.0, 0.110971),
+    line(98, "K", "K-M4,5", "Kb5", 130455.0, 0.00458101),
+    line(98, "K", "K-N2,3", "Kb2", 133319.0, 0.0589864),
+    line(98, "K", "K-N4,5", "Kb4", 133948.0, 0.00216011),
+    line(98, "L1", "L1-M2", "Lb4", 19665.0, 0.384809),
+    line(98, "L1", "L1-M3", "Lb3", 20894.0, 0.2961),
+    line(98, "L1", "L1-N2", "Lg2", 24390.0, 0.181043),
+    line(98, "L1", "L1-N3", "Lg3", 24718.0, 0.138047),
+    line(98, "L2", "L2-M1", "Ln", 18347.0, 0.0214733),
+    line(98, "L2", "L2-M4", "Lb1", 20624.0, 0.747906),
+    line(98, "L2", "L2-N4", "Lg1", 24117.0, 0.192678),
+    line(98, "L2", "L2-O4", "Lg6", 24767.0, 0.0379424),
+    line(98, "L3", "L3-M1", "Ll", 13146.0, 0.0496136),
+    line(98, "L3", "L3-M4", "La2", 15423.0, 0.0725602),
+    line(98, "L3", "L3-M5", "La1", 15660.0, 0.648391),
+    line(98, "L3", "L3-N1", "Lb6", 18094.0, 0.0134338),
+    line(98, "L3", "L3-N4,5", "Lb2,15", 18916.0, 0.173011),
+    line(98, "L3", "L3-O4,5", "Lb5", 19770.0, 0.0429904),
+    line(98, "M2", "M2-N4", "M2-N4", 5354.0, 1.0),
+    line(98, "M3", "M3-N5", "Mg", 4186.0, 1.0),
+    line(98, "M4", "M4,5-N2,3", "Mz", 2864.0, 0.00293255),
+    line(98, "M4", "M4-N6", "Mb", 3946.0, 0.997068),
+    line(98, "M5", "M5-N6,7", "Ma", 3709.0, 1.0),
+    line(99, "K", "K-L1", "Ka3", 111614.0, 0.00167465),
+    line(99, "K", "K-L2", "Ka2", 112531.0, 0.301278),
+    line(99, "K", "K-L3", "Ka1", 118012.0, 0.46354),
+    line(99, "K", "K-M2", "Kb3", 131838.0, 0.0562156),
+    line(99, "K", "K-M3", "Kb1", 133137.0, 0.111098),
+    line(99, "K", "K-M4,5", "Kb5", 133779.0, 0.0046597),
+    line(99, "K", "K-N2,3", "Kb2", 136766.0, 0.0592796),
+    line(99, "K", "K-N4,5", "Kb4", 137367.0, 0.00225529),
+    line(99, "L1", "L1-M2", "Lb4", 20224.0, 0.386979),
+    line(99, "L1", "L1-M3", "Lb3", 21523.0, 0.289674),
+    line(99, "L1", "L1-N2", "Lg2", 25152.0, 0.186198),
+    line(99, "L1", "L1-N3", "Lg3", 25446.0, 0.137148),
+    line(99, "L2", "L2-M1", "Ln", 18884.0, 0.0217467),
+    line(99, "L2", "L2-M4", "Lb1", 21248.0, 0.746521),
+    line(99, "L2", "L2-N4", "Lg1", 24836.0, 0.194376),
+    line(99, "L2", "L2-O4", "Lg6", 25508.0, 0.037356),
+    line(99, "L3", "L3-M1", "Ll", 13403.0, 0.0505824),
+    line(99, "L3", "L3-M4", "La2", 15767.0, 0.0724973),
+    line(99, "L3", "L3-M5", "La1", 16016.0, 0.647748),
+    line(99, "L3", "L3-N1", "Lb6", 18501.0, 0.0136282),
+    line(99, "L3", "L3-N4,5", "Lb2,15", 19355.0, 0.171609),
+    line(99, "L3", "L3-O4,5", "Lb5", 20242.0, 0.0439359),
+    line(99, "M2", "M2-N4", "M2-N4", 5529.0, 1.0),
+    line(99, "M3", "M3-N5", "Mg", 4294.0, 1.0),
+    line(99, "M4", "M4,5-N2,3", "Mz", 2987.0, 0.00293255),
+    line(99, "M4", "M4-N6", "Mb", 4053.0, 0.997068),
+    line(99, "M5", "M5-N6,7", "Ma", 3804.0, 1.0),
+    line(100, "K", "K-L1", "Ka3", 114352.0, 0.00178645),
+    line(100, "K", "K-L2", "Ka2", 115285.0, 0.30207),
+    line(100, "K", "K-L3", "Ka1", 121058.0, 0.462093),
+    line(100, "K", "K-M2", "Kb3", 135150.0, 0.056168),
+    line(100, "K", "K-M3", "Kb1", 136521.0, 0.111224),
+    line(100, "K", "K-M4,5", "Kb5", 137174.0, 0.00473789),
+    line(100, "K", "K-N2,3", "Kb2", 140177.0, 0.0595675),
+    line(100, "K", "K-N4,5", "Kb4", 140859.0, 0.00235302),
+    line(100, "L1", "L1-M2", "Lb4", 20798.0, 0.389042),
+    line(100, "L1", "L1-M3", "Lb3", 22169.0, 0.283319),
+    line(100, "L1", "L1-N2", "Lg2", 25825.0, 0.191424),
+    line(100, "L1", "L1-N3", "Lg3", 26195.0, 0.136214),
+    line(100, "L2", "L2-M1", "Ln", 19433.0, 0.0220235),
+    line(100, "L2", "L2-M4", "Lb1", 21889.0, 0.745296),
+    line(100, "L2", "L2-N4", "Lg1", 25574.0, 0.196011),
+    line(100, "L2", "L2-O4", "Lg6", 26268.0, 0.0366686),
+    line(100, "L3", "L3-M1", "Ll", 13660.0, 0.0516057),
+    line(100, "L3", "L3-M4", "La2", 16116.0, 0.0724856),
+    line(100, "L3", "L3-M5", "La1", 16377.0, 0.647561),
+    line(100, "L3", "L3-N1", "Lb6", 18916.0, 0.0138319),
+    line(100, "L3", "L3-N4,5", "Lb2,15", 19801.0, 0.169653),
+    line(100, "L3", "L3-O4,5", "Lb5", 20719.0, 0.0448631),
+    line(100, "M2", "M2-N4", "M2-N4", 5709.0, 1.0),
+    line(100, "M3", "M3-N5", "Mg", 4405.0, 1.0),
+    line(100, "M4", "M4,5-N2,3", "Mz", 3003.0, 0.00293255),
+    line(100, "M4", "M4-N6", "Mb", 4161.0, 0.997068),
+    line(100, "M5", "M5-N6,7", "Ma", 3900.0, 1.0)
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn element_lines() {
+        let fe = atomic_number("Fe").unwrap();
+        assert_eq!(fe, 26);
+        let ka1 = lines(fe).find(|l| l.siegbahn == "Ka1").unwrap();
+        assert_eq!((ka1.vacancy, ka1.energy), ("K", 6405.2));
+        assert!(lines(fe).any(|l| l.vacancy == "L3"));
+        assert_eq!(elements_in("Fe_K,Ca CO3,Ti=2%,Fe"), [26, 20, 22]);
+    }
+
+    #[test]
+    fn periodic_table_positions() {
+        assert_eq!(position(1), (0, 0));
+        assert_eq!(position(10), (1, 17));
+        assert_eq!(position(13), (2, 12));
+        assert_eq!(position(56), (5, 1));
+        assert_eq!(position(58), (7, 3));
+        assert_eq!(position(72), (5, 3));
+        assert_eq!(position(86), (5, 17));
+        assert_eq!(position(92), (8, 5));
+        let mut taken: Vec<(usize, usize)> = (1..=SYMBOLS.len() as u8).map(position).collect();
+        taken.sort();
+        taken.dedup();
+        assert_eq!(taken.len(), SYMBOLS.len());
+    }
+}